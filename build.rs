@@ -0,0 +1,58 @@
+//! Generates a test for every example input, which are included by `tests/examples.rs`.
+//!
+//! Example inputs are stored in `inputs/<year>/example`, and the tests of each year are generated
+//! in their own module, e.g. `year2023::day10_complex`.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+fn main() {
+    println!("cargo:rerun-if-changed=inputs");
+
+    let mut years = fs::read_dir("inputs")
+        .expect("Couldn't read the inputs directory")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u16>().ok())
+        .collect::<Vec<_>>();
+    years.sort();
+
+    let modules = years
+        .iter()
+        .map(|&year| {
+            let dir = PathBuf::from(format!("inputs/{year}/example"));
+            println!("cargo:rerun-if-changed={}", dir.display());
+
+            let tests = example_names(&dir)
+                .iter()
+                .map(|name| {
+                    let test_name = name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                    format!(
+                        "    #[test]\n    fn {test_name}() {{\n        super::check_example({year}, {name:?});\n    }}\n"
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            format!("mod year{year} {{\n{tests}}}\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, modules).expect("Couldn't write the generated example tests");
+}
+
+fn example_names(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut names = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| path.file_stem()?.to_str().map(String::from))
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
@@ -0,0 +1,259 @@
+//! Statistical benchmarking of individual parts.
+
+use std::any::Any;
+use std::fmt;
+use std::hint::black_box;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use clap::Args;
+use serde_json::{json, Value};
+
+use crate::cancel::with_timeout;
+use crate::input::{get_input, input_groups};
+use crate::report::{input_kind, print_header, Format};
+use crate::{format_params, parse_seconds, set_params, DynSolution, Opt, Part, REAL, VISUALIZE};
+
+/// How many times to run a part while benchmarking it.
+#[derive(Args, Clone, Debug)]
+pub struct BenchConfig {
+    /// Number of untimed runs before sampling starts
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Maximum number of timed runs
+    #[arg(long, default_value_t = 100)]
+    pub iterations: usize,
+
+    /// Stop sampling after this many seconds, even if not all iterations have run
+    #[arg(long, value_name = "SECONDS", default_value = "10", value_parser = parse_seconds)]
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            iterations: 100,
+            budget: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Summary statistics over the timed runs of a part.
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "Can't compute statistics without samples"
+        );
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let secs = sorted.iter().map(Duration::as_secs_f64).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = sorted[((0.95 * n as f64).ceil() as usize).clamp(1, n) - 1];
+
+        Stats {
+            samples: n,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+            min: sorted[0],
+            max: sorted[n - 1],
+        }
+    }
+}
+
+impl Stats {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "samples": self.samples,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "stddev_ns": self.stddev.as_nanos() as u64,
+            "p95_ns": self.p95.as_nanos() as u64,
+            "min_ns": self.min.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} samples: mean {:?}, median {:?}, stddev {:?}, p95 {:?} (min {:?}, max {:?})",
+            self.samples, self.mean, self.median, self.stddev, self.p95, self.min, self.max
+        )
+    }
+}
+
+/// Benchmarks a single part on an already parsed input.
+///
+/// At least one sample is always taken, even if the warmup already exhausted the time budget.
+pub fn bench_part(
+    solution: &dyn DynSolution,
+    part: Part,
+    parsed: &dyn Any,
+    config: &BenchConfig,
+) -> Stats {
+    let start = Instant::now();
+
+    for _ in 0..config.warmup {
+        black_box(solution.solve(part, parsed));
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations.max(1) {
+        let sample_start = Instant::now();
+        black_box(solution.solve(part, parsed));
+        samples.push(sample_start.elapsed());
+
+        if start.elapsed() >= config.budget {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// The statistics of every benchmarked part of a solution on a single input.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<&'static str>,
+    /// The name of the input file, without the extension.
+    pub input: String,
+    pub real: bool,
+    /// The statistics of each part, which are missing when the part timed out.
+    pub parts: Vec<(Part, Option<Stats>)>,
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> Value {
+        let parts = self
+            .parts
+            .iter()
+            .map(|(part, stats)| {
+                let mut json = match stats {
+                    Some(stats) => stats.to_json(),
+                    None => json!({}),
+                };
+                json["part"] = json!(part.number());
+                json["status"] = json!(if stats.is_some() { "ok" } else { "timed_out" });
+                json
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "title": self.title,
+            "input": self.input,
+            "kind": input_kind(self.real),
+            "parts": parts,
+        })
+    }
+}
+
+/// Benchmarks the selected parts of a solution, printing the statistics of each one.
+///
+/// Returns a report for every input that was used, since parts can have different example inputs.
+pub fn bench_with(
+    solution: &'static dyn DynSolution,
+    opt: &Opt,
+    config: &BenchConfig,
+) -> Result<Vec<BenchReport>> {
+    if opt.memory {
+        bail!("memory can't be measured while benchmarking, use `run --memory` instead");
+    }
+    REAL.store(opt.real, Ordering::Relaxed);
+    // rendering every iteration would swamp both the terminal and the timings
+    VISUALIZE.store(false, Ordering::Relaxed);
+    let changed = set_params(solution, opt)?;
+    let text = opt.format == Format::Text;
+    let year = opt.resolve_year(solution);
+
+    if text {
+        print_header(solution.day(), solution.title());
+        if !changed.is_empty() {
+            println!("--- with {}", format_params(&changed));
+        }
+    }
+
+    let mut reports = Vec::new();
+    for (name, parts) in input_groups(opt, solution) {
+        let input = get_input(opt, year, solution.day(), &name)?;
+        let parsed = solution.parse(&input);
+
+        let parts = parts
+            .into_iter()
+            .map(|part| {
+                if text {
+                    println!("---");
+                }
+                // the timeout applies to a single run, so only the first run is checked against it
+                // before benchmarking the part, which then counts as an extra warmup run
+                let finished = with_timeout(opt.timeout, {
+                    let parsed = Arc::clone(&parsed);
+                    move || black_box(solution.solve(part, parsed.as_ref()))
+                });
+                let stats = finished.map(|_| bench_part(solution, part, parsed.as_ref(), config));
+                if text {
+                    println!("Day {} Part {part} ({name})", solution.day());
+                    match &stats {
+                        Some(stats) => println!("--- {stats}"),
+                        None => println!("--- timed out"),
+                    }
+                }
+                (part, stats)
+            })
+            .collect::<Vec<_>>();
+
+        let report = BenchReport {
+            year,
+            day: solution.day(),
+            title: solution.title(),
+            input: name,
+            real: opt.real,
+            parts,
+        };
+        if !text {
+            println!("{}", report.to_json());
+        }
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
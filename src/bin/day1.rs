@@ -1,43 +1,5 @@
-use adventofcode_2023::runner;
-
-fn parse_input(input: &str, replace: bool) -> u32 {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            if replace {
-                line.to_string()
-                    .replace("one", "one1one")
-                    .replace("two", "two2two")
-                    .replace("three", "three3three")
-                    .replace("four", "four4four")
-                    .replace("five", "five5five")
-                    .replace("six", "six6six")
-                    .replace("seven", "seven7seven")
-                    .replace("eight", "eight8eight")
-                    .replace("nine", "nine9nine")
-            } else {
-                line.to_string()
-            }
-        })
-        .map(|line| {
-            line.chars()
-                .filter_map(|c| c.to_digit(10))
-                .collect::<Vec<u32>>()
-        })
-        .map(|vec| 10 * vec.first().unwrap() + vec.last().unwrap())
-        .sum()
-}
-
-fn part1(input: &str) {
-    println!("Day 1 Part 1: {}", parse_input(input, false));
-}
-
-fn part2(input: &str) {
-    println!("Day 1 Part 2: {}", parse_input(input, true));
-}
+use adventofcode_2023::{days::day1::Day1, run};
 
 fn main() {
-    runner(part1);
-    runner(part2);
+    run(&Day1);
 }
@@ -0,0 +1,96 @@
+//! Timing out parts, and letting long-running solutions stop early once they have been.
+//!
+//! A part that is run with a timeout gets its own thread and [`CancellationToken`]. When it
+//! doesn't finish in time the runner moves on and cancels the token, and solutions that poll
+//! [`cancelled`] in their long loops return early. Solutions that don't poll keep running in the
+//! background until the process exits, but they no longer hold up the runner.
+
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// The stack size of the threads that parts are run on, which matches the main thread on Linux so
+/// that recursive solutions behave the same with and without a timeout.
+const STACK_SIZE: usize = 8 << 20;
+
+/// Tells a part that it has timed out. Clones share the same state, so a token can be moved into
+/// worker threads, e.g. of rayon, which don't see the token of the part that started them.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static TOKEN: RefCell<CancellationToken> = RefCell::default();
+}
+
+/// The token of the part running on the current thread. A part run without a timeout is never
+/// cancelled.
+pub fn cancellation_token() -> CancellationToken {
+    TOKEN.with(|token| token.borrow().clone())
+}
+
+/// Whether the part running on the current thread has timed out, in which case its answer is
+/// discarded and it should return as soon as possible.
+pub fn cancelled() -> bool {
+    TOKEN.with(|token| token.borrow().is_cancelled())
+}
+
+/// Runs `f` on a new thread, returning its result if it finishes within `timeout`. Otherwise, the
+/// token of the thread is cancelled and `None` is returned without waiting for it. Without a
+/// timeout, `f` is simply run on the calling thread.
+///
+/// # Panics
+///
+/// If `f` panics, the panic is resumed on the calling thread.
+pub fn with_timeout<T, F>(timeout: Option<Duration>, f: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(f());
+    };
+
+    let token = CancellationToken::new();
+    let (tx, rx) = mpsc::channel();
+
+    let thread_token = token.clone();
+    let handle = thread::Builder::new()
+        .name("part".to_owned())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            TOKEN.with(|token| *token.borrow_mut() = thread_token);
+            // the runner has stopped listening if the part timed out
+            let _ = tx.send(f());
+        })
+        .expect("Couldn't spawn a thread to run the part on");
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            None
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the thread sends a result unless it panics"),
+        },
+    }
+}
@@ -0,0 +1,135 @@
+//! The HTTP client shared by everything that talks to Advent of Code.
+//!
+//! Following the site's automation guidelines, every request identifies the tool and a contact in
+//! its User-Agent, requests to the same site are spaced out by at least [`MIN_INTERVAL`], and
+//! responses that don't change are cached locally so that they are never fetched twice.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use std::{env, fs};
+
+use anyhow::{Context, Result};
+use hashbrown::HashMap;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::COOKIE;
+
+/// The minimum time between two requests to the same site.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+const REPOSITORY: &str = "github.com/dehanjl/adventofcode-2023";
+
+/// The time of the last request to each site, keyed by scheme, host and port.
+static LAST_REQUEST: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+pub fn client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        Client::builder()
+            .user_agent(user_agent())
+            .build()
+            .expect("Couldn't build the HTTP client")
+    })
+}
+
+/// Identifies the repository, and the contact given by `AOC_CONTACT` if it is set.
+pub fn user_agent() -> String {
+    match env::var("AOC_CONTACT") {
+        Ok(contact) if !contact.trim().is_empty() => format!("{REPOSITORY} by {}", contact.trim()),
+        _ => REPOSITORY.to_owned(),
+    }
+}
+
+pub fn get(url: &str, session: &str) -> reqwest::Result<Response> {
+    throttle(url);
+    client()
+        .get(url)
+        .header(COOKIE, format!("session={session}"))
+        .send()
+}
+
+pub fn post_form(url: &str, session: &str, form: &[(&str, String)]) -> reqwest::Result<Response> {
+    throttle(url);
+    client()
+        .post(url)
+        .header(COOKIE, format!("session={session}"))
+        .form(form)
+        .send()
+}
+
+/// Waits until at least [`MIN_INTERVAL`] has passed since the last request to the site of `url`.
+fn throttle(url: &str) {
+    let site = reqwest::Url::parse(url)
+        .map_or_else(|_| url.to_owned(), |url| url.origin().ascii_serialization());
+
+    // the lock is held while waiting, so that concurrent requests are spaced out as well
+    let mut last = LAST_REQUEST.lock().unwrap_or_else(|err| err.into_inner());
+    let last = last.get_or_insert_with(HashMap::new);
+    if let Some(previous) = last.get(&site) {
+        thread::sleep(MIN_INTERVAL.saturating_sub(previous.elapsed()));
+    }
+    last.insert(site, Instant::now());
+}
+
+/// A local cache of response bodies, stored as one file per URL.
+#[derive(Clone, Debug)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// The cache in `.cache` at the root of the repository.
+    pub fn standard() -> Self {
+        Cache::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".cache"))
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        let name = url
+            .split_once("://")
+            .map_or(url, |(_, rest)| rest)
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        self.dir.join(name)
+    }
+
+    /// The cached body of `url`, unless it is older than `max_age`.
+    pub fn get(&self, url: &str, max_age: Option<Duration>) -> Option<String> {
+        let path = self.path(url);
+        if let Some(max_age) = max_age {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            let age = SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+            if age > max_age {
+                return None;
+            }
+        }
+        fs::read_to_string(path).ok()
+    }
+
+    pub fn put(&self, url: &str, body: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(url), body)
+    }
+
+    /// Gets the body of `url` from the cache, or fetches and caches it when it isn't cached, or
+    /// is older than `max_age`. Only successful responses are cached.
+    pub fn fetch(&self, url: &str, session: &str, max_age: Option<Duration>) -> Result<String> {
+        if let Some(body) = self.get(url, max_age) {
+            return Ok(body);
+        }
+
+        let body = get(url, session)?.error_for_status()?.text()?;
+        self.put(url, &body)
+            .with_context(|| format!("Couldn't cache the response in {}", self.dir.display()))?;
+
+        Ok(body)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Answer, Part, Solution};
+
+fn parse_input(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn calibrate(lines: &[String], replace: bool) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            if replace {
+                line.to_string()
+                    .replace("one", "one1one")
+                    .replace("two", "two2two")
+                    .replace("three", "three3three")
+                    .replace("four", "four4four")
+                    .replace("five", "five5five")
+                    .replace("six", "six6six")
+                    .replace("seven", "seven7seven")
+                    .replace("eight", "eight8eight")
+                    .replace("nine", "nine9nine")
+            } else {
+                line.clone()
+            }
+        })
+        .map(|line| {
+            line.chars()
+                .filter_map(|c| c.to_digit(10))
+                .collect::<Vec<u32>>()
+        })
+        .map(|vec| 10 * vec.first().unwrap() + vec.last().unwrap())
+        .sum()
+}
+
+fn part1(lines: &[String]) -> u32 {
+    calibrate(lines, false)
+}
+
+fn part2(lines: &[String]) -> u32 {
+    calibrate(lines, true)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Trebuchet?!")
+    }
+
+    /// The example of the second part has lines without any digits, which the first part can't
+    /// handle.
+    fn example_input(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some("day1_part1"),
+            Part::Two => None,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
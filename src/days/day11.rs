@@ -0,0 +1,227 @@
+use crate::{visualize, Answer, Param, Solution};
+use grid::Grid;
+use hashbrown::HashMap;
+use itertools::Itertools;
+use priority_queue::DoublePriorityQueue;
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+const PART1_FACTOR: Param = Param {
+    name: "part1_factor",
+    default: 2,
+    min: 1,
+    // keeps the sum of the distances within an i64
+    max: 1_000_000_000,
+    description: "how many times larger empty rows and columns become in part 1",
+};
+const PART2_FACTOR: Param = Param {
+    name: "part2_factor",
+    default: 1_000_000,
+    min: 1,
+    // keeps the sum of the distances within an i64
+    max: 1_000_000_000,
+    description: "how many times larger empty rows and columns become in part 2",
+};
+
+fn display_grid(grid: &Grid<char>) {
+    for row in grid.iter_rows() {
+        for item in row {
+            eprint!("{item}");
+        }
+        eprintln!();
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Loc {
+    x: isize,
+    y: isize,
+}
+
+impl Loc {
+    fn neighbors(&self, grid: &Grid<char>) -> Vec<Loc> {
+        // down, right, up, left
+        const DIRMAP: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+        DIRMAP
+            .iter()
+            .map(|(dx, dy)| (self.x + dx, self.y + dy))
+            .filter_map(|(x, y)| {
+                if grid.get(y as usize, x as usize).is_some() {
+                    return Some(Loc { x, y });
+                }
+                None
+            })
+            .collect_vec()
+    }
+}
+
+/// The observed image, along with the rows and columns that contain no galaxies.
+pub struct Image {
+    grid: Grid<char>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+fn parse_input(input: &str) -> Image {
+    let cols = input.lines().next().unwrap().len();
+    let chars = input.lines().flat_map(|line| line.chars()).collect_vec();
+    let grid = Grid::from_vec(chars, cols);
+    let (empty_rows, empty_cols) = empty_indices(&grid);
+
+    Image {
+        grid,
+        empty_rows,
+        empty_cols,
+    }
+}
+
+fn empty_indices(grid: &Grid<char>) -> (Vec<usize>, Vec<usize>) {
+    let empty_rows = grid
+        .iter_rows()
+        .enumerate()
+        .filter_map(|(id, row)| {
+            if row.into_iter().all(|&c| c == '.') {
+                Some(id)
+            } else {
+                None
+            }
+        })
+        .collect_vec();
+
+    let empty_cols = grid
+        .iter_cols()
+        .enumerate()
+        .filter_map(|(id, col)| {
+            if col.into_iter().all(|&c| c == '.') {
+                Some(id)
+            } else {
+                None
+            }
+        })
+        .collect_vec();
+
+    (empty_rows, empty_cols)
+}
+
+fn a_star(start: &Loc, end: &Loc, image: &Image, factor: i64) -> i64 {
+    /// Manhattan distance heuristic function.
+    /// Because we can't step diagonally, this is admissable.
+    fn h(loc: &Loc, end: &Loc) -> i64 {
+        let dx = (loc.x - end.x).abs();
+        let dy = (loc.y - end.y).abs();
+        (dx + dy) as i64
+    }
+
+    let Image {
+        grid,
+        empty_rows,
+        empty_cols,
+    } = image;
+
+    let mut open_set: DoublePriorityQueue<Loc, i64> = DoublePriorityQueue::new();
+    let mut g_scores: HashMap<Loc, i64> = HashMap::new();
+    g_scores.insert(*start, 0);
+    let mut f_scores: HashMap<Loc, i64> = HashMap::new();
+    f_scores.insert(*start, h(start, end));
+
+    open_set.push(*start, *f_scores.get(start).unwrap());
+
+    while !open_set.is_empty() {
+        let current = open_set.pop_min().unwrap().0;
+        if current == *end {
+            return *g_scores.get(&current).unwrap();
+        }
+
+        for neighbor in current.neighbors(grid) {
+            let mut tentative_g_score = g_scores[&current] + 1;
+            if empty_rows.contains(&(neighbor.y as usize))
+                || empty_cols.contains(&(neighbor.x as usize))
+            {
+                tentative_g_score += factor - 1;
+            }
+
+            if tentative_g_score < *g_scores.get(&neighbor).unwrap_or(&i64::MAX) {
+                g_scores.insert(neighbor, tentative_g_score);
+                f_scores.insert(neighbor, tentative_g_score + h(&neighbor, end));
+                open_set.push(neighbor, *f_scores.get(&neighbor).unwrap());
+            }
+        }
+    }
+
+    i64::MAX
+}
+
+fn compute_distances(image: &Image, factor: i64) -> Vec<i64> {
+    if visualize() {
+        eprintln!(
+            "empty rows {:?} and columns {:?} are {factor} times larger",
+            image.empty_rows, image.empty_cols
+        );
+        display_grid(&image.grid);
+    }
+
+    let galaxies = image
+        .grid
+        .iter_rows()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.into_iter().enumerate().filter_map(move |(x, &c)| {
+                if c == '#' {
+                    Some(Loc {
+                        x: x as isize,
+                        y: y as isize,
+                    })
+                } else {
+                    None
+                }
+            })
+        })
+        .collect_vec();
+
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .par_bridge()
+        .map(|(a, b)| a_star(a, b, image, factor))
+        .collect()
+}
+
+fn part1(image: &Image) -> i64 {
+    let distances: Vec<i64> = compute_distances(image, PART1_FACTOR.get() as i64);
+    distances.iter().sum::<i64>()
+}
+
+fn part2(image: &Image) -> i64 {
+    let distances: Vec<i64> = compute_distances(image, PART2_FACTOR.get() as i64);
+    distances.iter().sum::<i64>()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Image;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Cosmic Expansion")
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[PART1_FACTOR, PART2_FACTOR]
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{cancellation_token, Answer, Param, Solution};
+use cached::proc_macro::cached;
+use cached::SizedCache;
+use itertools::Itertools;
+
+const UNFOLD: Param = Param {
+    name: "unfold",
+    default: 5,
+    min: 1,
+    // the number of arrangements of longer records can overflow a u64
+    max: 8,
+    description: "how many copies of each record there are in part 2",
+};
+
+fn parse_input(input: &str) -> Vec<(String, Vec<u64>)> {
+    input
+        .lines()
+        .map(|line| {
+            let (l, r) = line.split_once(' ').unwrap();
+
+            (
+                String::from(l),
+                r.split(',').map(|s| s.parse().unwrap()).collect_vec(),
+            )
+        })
+        .collect_vec()
+}
+
+fn compress_binary(mut input: usize) -> usize {
+    let mut output = 0;
+
+    while input != 0 {
+        let lead = input.leading_zeros();
+
+        output <<= lead.min(1);
+        output <<= 1;
+        output |= 1;
+
+        input <<= lead;
+        input <<= 1;
+    }
+
+    output
+}
+
+#[cached(
+    type = "SizedCache<String, u64>",
+    create = "{ SizedCache::with_size(100) }",
+    convert = r#"{ format!("{}{:?}", record, groups) }"#
+)]
+/// A port of https://www.reddit.com/r/adventofcode/comments/18hbbxe/2023_day_12python_stepbystep_tutorial_with_bonus/
+fn calc(record: &str, groups: &[u64]) -> u64 {
+    if groups.is_empty() {
+        if !record.contains('#') {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    if record.is_empty() {
+        return 0;
+    }
+
+    let next_character = &record[0..1];
+    let next_group = groups[0];
+
+    let pound = || {
+        let k = record.len().min(next_group as usize);
+        let this_group = &record[..k];
+
+        if this_group.replace('?', "#") != "#".repeat(next_group as usize) {
+            return 0;
+        }
+
+        if record.len() == next_group as usize {
+            if groups.len() == 1 {
+                return 1;
+            } else {
+                return 0;
+            }
+        }
+
+        if record[next_group as usize..].starts_with('#') {
+            return 0;
+        }
+
+        calc(&record[next_group as usize + 1..], &groups[1..])
+    };
+
+    let dot = || calc(&record[1..], groups);
+
+    match next_character {
+        "#" => pound(),
+        "." => dot(),
+        "?" => dot() + pound(),
+        _ => unreachable!(),
+    }
+}
+
+fn part1(records: &[(String, Vec<u64>)]) -> usize {
+    let token = cancellation_token();
+    let mut sum = 0;
+    for (s, v) in records.iter() {
+        let id = v.iter().fold(0, |x: usize, n| !(!x << n) << 1) >> 1;
+        // mask to check for 1s ('#')
+        let mask_a = s.chars().fold(0, |x: usize, c| match c {
+            '#' => (x << 1) | 1,
+            _ => x << 1,
+        });
+
+        // mask to check for 0s ('.')
+        let mask_b = !s.chars().fold(0, |x: usize, c| match c {
+            '#' | '?' => (x << 1) | 1,
+            _ => x << 1,
+        });
+
+        // there are 2^len arrangements, so check whether to give up every 2^16 of them
+        let k = (0..2_usize.pow(s.len() as u32))
+            .take_while(|val| val & 0xffff != 0 || !token.is_cancelled())
+            .filter(|val| (val & mask_a) == mask_a)
+            .filter(|val| (!val & mask_b) == mask_b)
+            .map(compress_binary)
+            .filter(|val| *val == id)
+            .count();
+
+        sum += k;
+    }
+    sum
+}
+
+fn part2(records: &[(String, Vec<u64>)]) -> u64 {
+    let unfold = UNFOLD.get() as usize;
+    let sum = records
+        .iter()
+        .map(|(l, r)| {
+            let s = vec![l.as_str(); unfold].join("?");
+            let v = r.repeat(unfold);
+            calc(&s, &v)
+        })
+        .sum::<u64>();
+    sum
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<(String, Vec<u64>)>;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Hot Springs")
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[UNFOLD]
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{visualize, Answer, Solution};
+use grid::Grid;
+use itertools::Itertools;
+
+fn display_grid(grid: &Grid<char>) {
+    for row in grid.iter_rows() {
+        for item in row {
+            eprint!("{item}");
+        }
+        eprintln!();
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Grid<char>> {
+    input
+        .split("\n\n")
+        .map(|s| {
+            let cols = s.lines().next().unwrap().len();
+            let chars = s.lines().flat_map(|line| line.chars()).collect_vec();
+            Grid::from_vec(chars, cols)
+        })
+        .collect_vec()
+}
+
+fn get_reflection_axis(v: &[Vec<&char>]) -> Vec<usize> {
+    let mut axis = Vec::new();
+    for i in 1..v.len() {
+        let l_min = (2 * i).saturating_sub(v.len());
+        let l = v[l_min..i].iter().collect_vec();
+        let r_max = v.len().min(i + l.len());
+        let r = v[i..r_max].iter().rev().collect_vec();
+
+        if l == r {
+            axis.push(i);
+        }
+    }
+    axis
+}
+
+fn part1(grids: &[Grid<char>]) -> usize {
+    let mut sum = 0;
+    for grid in grids.iter() {
+        let hor = grid.iter_rows().map(|v| v.collect_vec()).collect_vec();
+        let ver = grid.iter_cols().map(|v| v.collect_vec()).collect_vec();
+
+        let val = get_reflection_axis(&hor).first().unwrap_or(&0) * 100
+            + get_reflection_axis(&ver).first().unwrap_or(&0);
+        if visualize() {
+            display_grid(grid);
+            eprintln!("summarized as {val}\n");
+        }
+
+        sum += val;
+    }
+    sum
+}
+
+fn part2(grids: &[Grid<char>]) -> usize {
+    let mut sum = 0;
+    for grid in grids.iter() {
+        let hor = grid.iter_rows().map(|v| v.collect_vec()).collect_vec();
+        let ver = grid.iter_cols().map(|v| v.collect_vec()).collect_vec();
+
+        let mut val = 0;
+        if let Some(h) = get_reflection_axis(&hor).first() {
+            val = *h * 100;
+        }
+        if let Some(v) = get_reflection_axis(&ver).first() {
+            val = *v;
+        }
+
+        let mut smudge = None;
+        'inner: for (i, j) in (0..grid.rows()).cartesian_product(0..grid.cols()) {
+            let mut g = grid.clone();
+            let k = g.get_mut(i, j).unwrap();
+            if k == &'#' {
+                *k = '.';
+            } else {
+                *k = '#';
+            }
+
+            let hor_g = g.iter_rows().map(|v| v.collect_vec()).collect_vec();
+            let ver_g = g.iter_cols().map(|v| v.collect_vec()).collect_vec();
+
+            for h in get_reflection_axis(&hor_g) {
+                if val != h * 100 {
+                    val = h * 100;
+                    smudge = Some((i, j));
+                    break 'inner;
+                }
+            }
+
+            for v in get_reflection_axis(&ver_g) {
+                if val != v {
+                    val = v;
+                    smudge = Some((i, j));
+                    break 'inner;
+                }
+            }
+        }
+
+        if visualize() {
+            display_grid(grid);
+            if let Some((i, j)) = smudge {
+                eprintln!("with the smudge at row {i} and column {j} fixed, summarized as {val}\n");
+            }
+        }
+
+        sum += val;
+    }
+    sum
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Grid<char>>;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Point of Incidence")
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{visualize, Answer, Param, Solution};
+use grid::Grid;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+const MAX_CYCLES: Param = Param {
+    name: "cycles",
+    default: 1_000_000_000,
+    min: 0,
+    max: usize::MAX as u64,
+    description: "the number of spin cycles in part 2",
+};
+
+fn display_grid(grid: &Grid<char>) {
+    for row in grid.iter_rows() {
+        for item in row {
+            eprint!("{item}");
+        }
+        eprintln!();
+    }
+}
+
+fn parse_input(input: &str) -> Grid<char> {
+    let cols = input.lines().next().unwrap().len();
+    let chars = input.lines().flat_map(|line| line.chars()).collect_vec();
+    Grid::from_vec(chars, cols)
+}
+
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+impl Direction {
+    /// Moving off the top or left edge wraps around to an out of bounds index,
+    /// which is rejected by `Grid::get`.
+    fn adjust_idx(&self, row: usize, col: usize) -> (usize, usize) {
+        match *self {
+            Self::North => (row.wrapping_sub(1), col),
+            Self::West => (row, col.wrapping_sub(1)),
+            Self::South => (row + 1, col),
+            Self::East => (row, col + 1),
+        }
+    }
+}
+
+/// Finds the new pos of a rock if it is moved as far as possible in the given direction.
+fn find_dest(
+    mut row: usize,
+    mut col: usize,
+    grid: &Grid<char>,
+    dir: Direction,
+) -> Option<(usize, usize)> {
+    (row, col) = dir.adjust_idx(row, col);
+
+    let mut ret = None;
+    while let Some(&c) = grid.get(row, col) {
+        if c != '.' {
+            break;
+        };
+        ret = Some((row, col));
+        (row, col) = dir.adjust_idx(row, col);
+    }
+
+    ret
+}
+
+/// Rolls a single spot in the grid in the given direction. Only rolls it if it is round ('O').
+fn roll(grid: &mut Grid<char>, row: usize, col: usize, dir: Direction) {
+    if grid[(row, col)] == 'O' {
+        if let Some((new_row, new_col)) = find_dest(row, col, grid, dir) {
+            grid[(new_row, new_col)] = 'O';
+            grid[(row, col)] = '.';
+        }
+    }
+}
+
+fn weight(grid: &Grid<char>) -> usize {
+    grid.iter_rows()
+        .enumerate()
+        .map(|(i, row)| row.into_iter().filter(|&&item| item == 'O').count() * (grid.rows() - i))
+        .sum::<usize>()
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
+
+    for i in 0..grid.rows() {
+        for j in 0..grid.cols() {
+            roll(&mut grid, i, j, Direction::North);
+        }
+    }
+    show_tilt(&grid, "north");
+
+    weight(&grid)
+}
+
+/// Renders the platform after it was tilted, when visualizing.
+fn show_tilt(grid: &Grid<char>, direction: &str) {
+    if visualize() {
+        eprintln!("tilted {direction}:");
+        display_grid(grid);
+    }
+}
+
+fn roll_cycle(grid: &mut Grid<char>) {
+    for i in 0..grid.rows() {
+        for j in 0..grid.cols() {
+            roll(grid, i, j, Direction::North);
+        }
+    }
+    show_tilt(grid, "north");
+
+    for j in 0..grid.cols() {
+        for i in 0..grid.rows() {
+            roll(grid, i, j, Direction::West);
+        }
+    }
+    show_tilt(grid, "west");
+
+    for i in (0..grid.rows()).rev() {
+        for j in 0..grid.cols() {
+            roll(grid, i, j, Direction::South);
+        }
+    }
+    show_tilt(grid, "south");
+
+    for j in (0..grid.cols()).rev() {
+        for i in 0..grid.rows() {
+            roll(grid, i, j, Direction::East);
+        }
+    }
+    show_tilt(grid, "east");
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    let max_cycles = MAX_CYCLES.get() as usize;
+    let mut grid = grid.clone();
+
+    let mut store: HashMap<Vec<char>, usize> = HashMap::new();
+    let (mut cycle_start, mut cycle_len) = (usize::MAX, usize::MAX);
+    for idx in 1..=max_cycles {
+        if visualize() {
+            eprintln!("cycle {idx}:");
+        }
+        roll_cycle(&mut grid);
+        let k = grid.clone().into_vec();
+        if let Some(&n) = store.get(&k) {
+            (cycle_start, cycle_len) = (n, idx - n);
+            break;
+        }
+        store.insert(k, idx);
+    }
+    if cycle_len == usize::MAX {
+        // the grid didn't repeat within the given number of cycles
+        return weight(&grid);
+    }
+    let n = (max_cycles - cycle_start) / cycle_len;
+    let idx = max_cycles - (n * cycle_len);
+    let cycled_grid = Grid::from_vec(
+        store.iter().find(|(_, v)| **v == idx).unwrap().0.to_owned(),
+        grid.cols(),
+    );
+
+    weight(&cycled_grid)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Grid<char>;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Parabolic Reflector Dish")
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[MAX_CYCLES]
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
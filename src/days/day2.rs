@@ -0,0 +1,120 @@
+use crate::{Answer, Param, Solution};
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+const MAX_R: Param = Param {
+    name: "max_r",
+    default: 12,
+    min: 0,
+    max: u32::MAX as u64,
+    description: "the number of red cubes in the bag",
+};
+const MAX_G: Param = Param {
+    name: "max_g",
+    default: 13,
+    min: 0,
+    max: u32::MAX as u64,
+    description: "the number of green cubes in the bag",
+};
+const MAX_B: Param = Param {
+    name: "max_b",
+    default: 14,
+    min: 0,
+    max: u32::MAX as u64,
+    description: "the number of blue cubes in the bag",
+};
+type Colors = (u32, u32, u32);
+
+fn parse_game(game: &str) -> Vec<Colors> {
+    game.trim()
+        .split(';')
+        .map(|set| {
+            let (mut r, mut g, mut b) = (0, 0, 0);
+            set.trim().split(',').for_each(|s| {
+                let (v, c) = sscanf::scanf!(s.trim(), "{u32} {str}").unwrap();
+                match c {
+                    "red" => r += v,
+                    "green" => g += v,
+                    "blue" => b += v,
+                    _ => panic!("Invalid color"),
+                }
+            });
+
+            (r, g, b)
+        })
+        .collect_vec()
+}
+
+fn parse_input(input: &str) -> HashMap<usize, Vec<Colors>> {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_once(':').unwrap();
+            let id = sscanf::scanf!(left, "Game {usize}").unwrap();
+            let game = parse_game(right);
+            (id, game)
+        })
+        .collect::<HashMap<usize, Vec<Colors>>>()
+}
+
+fn part1(games: &HashMap<usize, Vec<Colors>>) -> usize {
+    let (max_r, max_g, max_b) = (MAX_R.get(), MAX_G.get(), MAX_B.get());
+    let mut impossible_ids = Vec::new();
+
+    'outer: for (id, game) in games.iter() {
+        for (r, g, b) in game.iter() {
+            if u64::from(*r) > max_r || u64::from(*g) > max_g || u64::from(*b) > max_b {
+                impossible_ids.push(*id);
+                continue 'outer;
+            }
+        }
+    }
+
+    let sum_game_ids = games.keys().sum::<usize>();
+
+    sum_game_ids - impossible_ids.iter().sum::<usize>()
+}
+
+fn part2(games: &HashMap<usize, Vec<Colors>>) -> u32 {
+    let sum_powers = games
+        .iter()
+        .map(|(_, game)| {
+            let max_r = game.iter().map(|&(r, _, _)| r).max().unwrap();
+            let max_g = game.iter().map(|&(_, g, _)| g).max().unwrap();
+            let max_b = game.iter().map(|&(_, _, b)| b).max().unwrap();
+            max_r * max_g * max_b
+        })
+        .sum::<u32>();
+
+    sum_powers
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = HashMap<usize, Vec<Colors>>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Cube Conundrum")
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[MAX_R, MAX_G, MAX_B]
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
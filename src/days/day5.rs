@@ -0,0 +1,126 @@
+use crate::{cancellation_token, Answer, Solution};
+use indicatif::{ParallelProgressIterator, ProgressBar, ProgressStyle};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+type Seed = i64;
+
+#[derive(Debug)]
+pub struct Map(Vec<(i64, i64, i64)>);
+
+impl Map {
+    fn map(&self, source: i64) -> i64 {
+        let mut destination = source;
+
+        for (drs, srs, l) in self.0.iter() {
+            let offset = drs - srs;
+            if source >= *srs && source < srs + l {
+                destination = source + offset;
+                break;
+            }
+        }
+
+        destination
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<Seed>, Vec<Map>) {
+    let blocks = input.split("\n\n").collect_vec();
+    let seeds = blocks
+        .first()
+        .unwrap()
+        .split_once(": ")
+        .unwrap()
+        .1
+        .split(' ')
+        .map(|s| s.parse::<Seed>().unwrap())
+        .collect_vec();
+
+    let maps = blocks
+        .iter()
+        .skip(1)
+        .map(|block| {
+            let map = block
+                .lines()
+                .skip(1)
+                .map(|line| sscanf::scanf!(line, "{} {} {}", i64, i64, i64).unwrap())
+                .collect_vec();
+
+            Map(map)
+        })
+        .collect_vec();
+
+    (seeds, maps)
+}
+
+fn map_through(maps: &[Map], seed: Seed) -> i64 {
+    maps.iter().fold(seed, |acc, map| map.map(acc))
+}
+
+fn part1((seeds, maps): &(Vec<Seed>, Vec<Map>)) -> i64 {
+    let loc = seeds
+        .iter()
+        .map(|&seed| map_through(maps, seed))
+        .min()
+        .unwrap();
+
+    loc
+}
+
+fn part2((seeds, maps): &(Vec<Seed>, Vec<Map>)) -> i64 {
+    let style = ProgressStyle::default_bar()
+        .template("[{bar:40}] {percent}% {per_sec}")
+        .unwrap()
+        .progress_chars("#>-");
+
+    let ranges = seeds
+        .iter()
+        .tuples()
+        .map(|(&start, &len)| start..start + len)
+        .collect_vec();
+    let seed_count = ranges
+        .iter()
+        .map(|range| range.end - range.start)
+        .sum::<i64>();
+
+    // the seeds are expanded lazily, since there are far too many to keep in memory at once
+    let token = cancellation_token();
+    let loc = ranges
+        .par_iter()
+        .flat_map_iter(|range| range.clone().take_while(|_| !token.is_cancelled()))
+        .progress_with(ProgressBar::new(seed_count as u64).with_style(style))
+        .map(|seed| map_through(maps, seed))
+        .min();
+
+    if token.is_cancelled() {
+        // the answer is discarded anyway
+        return 0;
+    }
+    loc.expect("there should be at least one seed")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Vec<Seed>, Vec<Map>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("If You Give A Seed A Fertilizer")
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Answer, Solution};
+use itertools::Itertools;
+
+fn parse(text: &str) -> Vec<u64> {
+    let re = regex::Regex::new(r"\b\d+\b").unwrap();
+    re.captures_iter(text)
+        .map(|cap| cap.get(0).unwrap().as_str().parse::<u64>().unwrap())
+        .collect::<Vec<u64>>()
+}
+
+fn concat(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .map(|&n| n.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+fn parse_input(input: &str) -> (Vec<u64>, Vec<u64>) {
+    let (times, dists) = input.lines().collect_tuple().unwrap();
+    (parse(times), parse(dists))
+}
+
+fn part1((times, dists): &(Vec<u64>, Vec<u64>)) -> u64 {
+    let res = times
+        .iter()
+        .zip(dists.iter())
+        .map(|(time, dist)| {
+            (0..=*time)
+                .map(|t| t * (time - t))
+                .filter(|t| t > dist)
+                .count() as u64
+        })
+        .product::<u64>();
+
+    res
+}
+
+fn part2((times, dists): &(Vec<u64>, Vec<u64>)) -> u64 {
+    let (time, dist) = (concat(times), concat(dists));
+
+    (0..=time)
+        .map(|t| t * (time - t))
+        .filter(|&t| t > dist)
+        .count() as u64
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = (Vec<u64>, Vec<u64>);
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Wait For It")
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{Answer, Solution};
+use fnv::FnvHashMap;
+use itertools::Itertools;
+
+type Card = char;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand(String);
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    FiveKind = 7_000_000,
+    FourKind = 6_000_000,
+    FullHouse = 5_000_000,
+    ThreeKind = 4_000_000,
+    TwoPair = 3_000_000,
+    OnePair = 2_000_000,
+    HighCard = 1_000_000,
+}
+
+impl Hand {
+    /// Relabels the cards so that the hand can be scored as a hexadecimal number,
+    /// with jacks replaced by the given card.
+    fn relabel(&self, jack: Card) -> Hand {
+        Hand(
+            self.0
+                .chars()
+                .map(|card| match card {
+                    'A' => 'E',
+                    'K' => 'D',
+                    'Q' => 'C',
+                    'J' => jack,
+                    'T' => 'A',
+                    _ => card,
+                })
+                .collect(),
+        )
+    }
+
+    fn high_pair(counts: &FnvHashMap<Card, usize>) -> (usize, usize) {
+        let (mut first, mut second) = (0, 0);
+        for &count in counts.values() {
+            if count > first {
+                second = first;
+                first = count;
+            } else if count > second {
+                second = count;
+            }
+        }
+        (first, second)
+    }
+
+    fn match_pair(first: usize, second: usize) -> HandType {
+        match (first, second) {
+            (5, _) => HandType::FiveKind,
+            (4, _) => HandType::FourKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    fn get_type(&self) -> HandType {
+        let mut counts = FnvHashMap::with_capacity_and_hasher(5, Default::default());
+        for card in self.0.chars() {
+            *counts.entry(card).or_insert(0) += 1;
+        }
+
+        let (first, second) = Hand::high_pair(&counts);
+        Hand::match_pair(first, second)
+    }
+
+    fn score(&self) -> u32 {
+        self.get_type() as u32 + u32::from_str_radix(&self.0, 16).unwrap()
+    }
+
+    fn get_type_joker(&self) -> HandType {
+        let mut counts = FnvHashMap::with_capacity_and_hasher(5, Default::default());
+        for card in self.0.chars() {
+            *counts.entry(card).or_insert(0) += 1;
+        }
+
+        let joker_count = counts.remove(&'1').unwrap_or(0);
+
+        let (first, second) = Hand::high_pair(&counts);
+        Hand::match_pair(first + joker_count, second)
+    }
+
+    fn score_joker(&self) -> u32 {
+        self.get_type_joker() as u32 + u32::from_str_radix(&self.0, 16).unwrap()
+    }
+}
+
+fn parse_input(input: &str) -> Vec<(Hand, u32)> {
+    input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            (Hand(hand.to_string()), bid.parse::<u32>().unwrap())
+        })
+        .collect_vec()
+}
+
+fn part1(hands: &[(Hand, u32)]) -> u32 {
+    let mut hands = hands
+        .iter()
+        .map(|(hand, bid)| (hand.relabel('B'), *bid))
+        .collect_vec();
+    hands.sort_by_cached_key(|(hand, _)| hand.score());
+
+    let winnings = hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| bid * (i as u32 + 1))
+        .sum::<u32>();
+
+    winnings
+}
+
+fn part2(hands: &[(Hand, u32)]) -> u32 {
+    let mut hands = hands
+        .iter()
+        .map(|(hand, bid)| (hand.relabel('1'), *bid))
+        .collect_vec();
+    hands.sort_by_cached_key(|(hand, _)| hand.score_joker());
+
+    let winnings = hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| bid * (i as u32 + 1))
+        .sum::<u32>();
+
+    winnings
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<(Hand, u32)>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Camel Cards")
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{cancelled, Answer, Part, Solution};
+use hashbrown::HashMap;
+use itertools::Itertools;
+use num::integer::lcm;
+
+fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
+    let (steps, map) = input.split_once("\n\n").unwrap();
+
+    let nodes = map
+        .lines()
+        .map(|line| sscanf::scanf!(line, "{str} = ({str}, {str})").unwrap())
+        .map(|(a, b, c)| (a.to_owned(), (b.to_owned(), c.to_owned())))
+        .collect::<HashMap<_, _>>();
+
+    (steps.chars().collect_vec(), nodes)
+}
+
+fn part1((steps, nodes): &(Vec<char>, HashMap<String, (String, String)>)) -> usize {
+    let mut curr_node = "AAA";
+    let mut curr_steps = 0;
+
+    loop {
+        // ZZZ might never be reached, in which case this only ends by timing out
+        if curr_node == "ZZZ" || cancelled() {
+            break curr_steps;
+        }
+
+        let side = steps[curr_steps % steps.len()];
+        curr_node = if side == 'L' {
+            nodes.get(curr_node).unwrap().0.as_str()
+        } else {
+            nodes.get(curr_node).unwrap().1.as_str()
+        };
+
+        curr_steps += 1;
+    }
+}
+
+fn part2((steps, nodes): &(Vec<char>, HashMap<String, (String, String)>)) -> u64 {
+    let start_nodes = nodes.keys().filter(|k| k.ends_with('A')).collect_vec();
+
+    let lcm = start_nodes
+        .iter()
+        .map(|&node| {
+            let mut curr_node = node;
+            let mut curr_steps = 0;
+
+            loop {
+                if curr_node.ends_with('Z') || cancelled() {
+                    break;
+                }
+
+                let side = steps[curr_steps % steps.len()];
+                curr_node = if side == 'L' {
+                    &nodes.get(curr_node).unwrap().0
+                } else {
+                    &nodes.get(curr_node).unwrap().1
+                };
+
+                curr_steps += 1;
+            }
+
+            curr_steps as u64
+        })
+        .fold(1, lcm);
+
+    lcm
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = (Vec<char>, HashMap<String, (String, String)>);
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Haunted Wasteland")
+    }
+
+    /// The example of the second part has no `AAA` node to start the first part from.
+    fn example_input(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => Some("day8_part1"),
+            Part::Two => None,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Answer, Solution};
+use itertools::Itertools;
+
+fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|x| x.parse::<i32>().unwrap())
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn part1(sequences: &[Vec<i32>]) -> i32 {
+    let res = sequences
+        .iter()
+        .map(|orig_seq| {
+            let mut seq = orig_seq.clone();
+            let mut intermediate_seqs = vec![];
+            loop {
+                let diffs = seq.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+                if diffs.iter().all(|&x| x == 0) {
+                    break;
+                }
+
+                intermediate_seqs.push(diffs.clone());
+                seq = diffs;
+            }
+            let final_diff = intermediate_seqs
+                .iter()
+                .map(|v| v.last().unwrap())
+                .sum::<i32>();
+            (orig_seq, final_diff)
+        })
+        .map(|(seq, diff)| seq.last().unwrap() + diff)
+        .sum::<i32>();
+
+    res
+}
+
+fn part2(sequences: &[Vec<i32>]) -> i32 {
+    let res = sequences
+        .iter()
+        .map(|orig_seq| {
+            let mut seq = orig_seq.clone();
+            let mut intermediate_seqs = vec![];
+            loop {
+                let diffs = seq.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+                if diffs.iter().all(|&x| x == 0) {
+                    break;
+                }
+
+                intermediate_seqs.push(diffs.clone());
+                seq = diffs;
+            }
+            let first_diff = intermediate_seqs
+                .iter()
+                .rev()
+                .map(|v| v.first().unwrap())
+                .fold(0, |acc, &x| x - acc);
+            (orig_seq, first_diff)
+        })
+        .map(|(seq, diff)| seq.first().unwrap() - diff)
+        .sum::<i32>();
+
+    res
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Mirage Maintenance")
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {
+        part2(input).into()
+    }
+}
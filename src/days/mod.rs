@@ -0,0 +1,43 @@
+use crate::DynSolution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+static REGISTRY: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+];
+
+/// All implemented days, ordered by day number.
+pub fn registry() -> &'static [&'static dyn DynSolution] {
+    REGISTRY
+}
+
+/// Looks up the solution for a single day.
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    REGISTRY.iter().find(|s| s.day() == day).copied()
+}
@@ -0,0 +1,197 @@
+//! A local history of benchmark results, used to spot performance regressions between commits.
+//!
+//! Every benchmarked part is appended as one JSON object per line to `bench_history.jsonl`, along
+//! with the git commit and a timestamp.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use crate::bench::Stats;
+use crate::Part;
+
+/// A single benchmarked part, as stored in the history file.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub commit: Option<String>,
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The name of the input file, without the extension.
+    pub input: String,
+    pub kind: String,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Entry {
+    pub fn new(year: u16, day: u8, part: Part, input: &str, kind: &str, stats: &Stats) -> Self {
+        Entry {
+            commit: git_commit(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            year,
+            day,
+            part,
+            input: input.to_owned(),
+            kind: kind.to_owned(),
+            median: stats.median,
+            mean: stats.mean,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "commit": self.commit,
+            "timestamp": self.timestamp,
+            "year": self.year,
+            "day": self.day,
+            "part": self.part.number(),
+            "input": self.input,
+            "kind": self.kind,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+        })
+    }
+
+    fn from_json(json: &Value) -> Option<Self> {
+        let nanos = |key: &str| json.get(key)?.as_u64().map(Duration::from_nanos);
+
+        Some(Entry {
+            commit: json.get("commit")?.as_str().map(String::from),
+            timestamp: json.get("timestamp")?.as_u64()?,
+            year: json.get("year")?.as_u64()?.try_into().ok()?,
+            day: json.get("day")?.as_u64()?.try_into().ok()?,
+            part: match json.get("part")?.as_u64()? {
+                1 => Part::One,
+                2 => Part::Two,
+                _ => return None,
+            },
+            input: json.get("input")?.as_str()?.to_owned(),
+            kind: json.get("kind")?.as_str()?.to_owned(),
+            median: nanos("median_ns")?,
+            mean: nanos("mean_ns")?,
+        })
+    }
+
+    /// Whether two entries measure the same thing, and can therefore be compared.
+    fn same_target(&self, other: &Entry) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.part == other.part
+            && self.input == other.input
+            && self.kind == other.kind
+    }
+}
+
+pub fn history_path() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("bench_history.jsonl");
+    path
+}
+
+pub fn append(entries: &[Entry]) -> Result<()> {
+    let path = history_path();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Couldn't open the benchmark history at {}", path.display()))?;
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_json())?;
+    }
+
+    Ok(())
+}
+
+/// Loads every entry in the history, oldest first. Lines that can't be parsed are skipped.
+pub fn load() -> Result<Vec<Entry>> {
+    let path = history_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("Couldn't read the benchmark history at {}", path.display()))?;
+
+    Ok(text
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|json| Entry::from_json(&json))
+        .collect())
+}
+
+/// The latest result of a part compared against a baseline result of the same part.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub baseline: Entry,
+    pub current: Entry,
+    /// The relative change of the median, where `0.1` means 10% slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compares the latest entry of every benchmarked part against a baseline.
+///
+/// The baseline is the latest entry from a commit starting with `baseline`, or when no commit is
+/// given, the latest entry from any commit other than the one the current entry was made at.
+/// A part regressed when its median got slower by more than `threshold`, e.g. `0.05` for 5%.
+pub fn compare(entries: &[Entry], baseline: Option<&str>, threshold: f64) -> Vec<Comparison> {
+    let mut comparisons: Vec<Comparison> = Vec::new();
+
+    for (i, current) in entries.iter().enumerate().rev() {
+        if comparisons.iter().any(|c| c.current.same_target(current)) {
+            continue;
+        }
+
+        let baseline = entries[..i].iter().rev().find(|entry| {
+            entry.same_target(current)
+                && match baseline {
+                    Some(commit) => entry
+                        .commit
+                        .as_deref()
+                        .is_some_and(|c| c.starts_with(commit)),
+                    None => entry.commit != current.commit,
+                }
+        });
+
+        if let Some(baseline) = baseline {
+            let change = current.median.as_secs_f64()
+                / baseline.median.as_secs_f64().max(f64::EPSILON)
+                - 1.0;
+            comparisons.push(Comparison {
+                baseline: baseline.clone(),
+                current: current.clone(),
+                change,
+                regressed: change > threshold,
+            });
+        }
+    }
+
+    comparisons.sort_by_key(|c| (c.current.day, c.current.part, c.current.input.clone()));
+    comparisons
+}
+
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|s| s.trim().to_owned())
+}
@@ -0,0 +1,261 @@
+//! Finding, reading and downloading puzzle inputs.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use reqwest::StatusCode;
+use thiserror::Error;
+
+use crate::client;
+use crate::session::{get_session_token, SessionError};
+use crate::{DynSolution, Opt, Part, BASE_URL};
+
+/// The `--input` path that reads the input from stdin.
+const STDIN: &str = "-";
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("couldn't find the example input at {}", .0.display())]
+    MissingExample(PathBuf),
+
+    #[error("couldn't access {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("couldn't read the input from stdin: {0}")]
+    Stdin(#[source] io::Error),
+
+    #[error(transparent)]
+    Session(#[from] SessionError),
+
+    #[error("the request for the input failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("the session token was rejected, it may have expired")]
+    SessionRejected,
+
+    #[error("day {day} of {year} isn't unlocked yet")]
+    NotUnlocked { year: u16, day: u8 },
+
+    #[error("the server responded with {0}")]
+    Status(StatusCode),
+
+    #[error("the server responded with an HTML page instead of a puzzle input")]
+    Html,
+
+    #[error("the server responded with an empty puzzle input")]
+    Empty,
+}
+
+impl InputError {
+    fn io(path: &Path) -> impl FnOnce(io::Error) -> InputError + '_ {
+        move |source| InputError::Io {
+            path: path.to_owned(),
+            source,
+        }
+    }
+}
+
+/// Reads the input of a day, downloading and saving the real input if it doesn't exist yet.
+///
+/// The input is normalized, and a warning is written to stderr when that changed anything.
+pub(crate) fn get_input(opt: &Opt, year: u16, day: u8, name: &str) -> Result<String, InputError> {
+    let input = read_input(opt, year, day, name)?;
+    let (input, changes) = normalize(&input);
+    if !changes.is_empty() {
+        eprintln!(
+            "Warning: normalized the input `{name}`: {}",
+            changes.join(", ")
+        );
+    }
+
+    Ok(input)
+}
+
+/// Reads the real input of a day, downloading and saving it if it doesn't exist yet.
+pub fn real_input(year: u16, day: u8) -> Result<String, InputError> {
+    let opt = Opt {
+        real: true,
+        ..Opt::default()
+    };
+    read_input(&opt, year, day, &format!("day{day}"))
+}
+
+fn read_input(opt: &Opt, year: u16, day: u8, name: &str) -> Result<String, InputError> {
+    match opt.input.as_deref() {
+        Some(path) if path == Path::new(STDIN) => return read_stdin(),
+        Some(path) => return fs::read_to_string(path).map_err(InputError::io(path)),
+        None => {}
+    }
+
+    let path = make_path(year, name, opt);
+    match (path.exists(), opt.real) {
+        (true, _) => fs::read_to_string(&path).map_err(InputError::io(&path)),
+        (false, false) => Err(InputError::MissingExample(path)),
+        (false, true) => download_and_save(&path, year, day),
+    }
+}
+
+/// Normalizes an input so that files saved on other platforms parse the same way, by removing a
+/// byte order mark, converting CRLF line endings to LF, and removing trailing blank lines.
+///
+/// Returns the normalized input, and a description of each change that was made.
+pub fn normalize(input: &str) -> (String, Vec<&'static str>) {
+    let mut changes = Vec::new();
+
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push("removed the byte order mark");
+            rest
+        }
+        None => input,
+    };
+
+    let mut input = if input.contains("\r\n") {
+        changes.push("converted CRLF line endings to LF");
+        input.replace("\r\n", "\n")
+    } else {
+        input.to_owned()
+    };
+
+    let trimmed = input.trim_end_matches(['\n', ' ', '\t']).len();
+    // keep the final newline of the last line, if there was one
+    let end = input[trimmed..]
+        .find('\n')
+        .map_or(input.len(), |i| trimmed + i + 1);
+    if end < input.len() {
+        changes.push("removed trailing blank lines");
+        input.truncate(end);
+    }
+
+    (input, changes)
+}
+
+/// The selected parts of a solution, grouped by the name of their input, in the order of the parts.
+pub(crate) fn input_groups(opt: &Opt, solution: &dyn DynSolution) -> Vec<(String, Vec<Part>)> {
+    let mut groups: Vec<(String, Vec<Part>)> = Vec::new();
+    for &part in opt.part.parts() {
+        let name = input_name(opt, solution, part);
+        match groups.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, parts)) => parts.push(part),
+            None => groups.push((name, vec![part])),
+        }
+    }
+    groups
+}
+
+/// The name of the input file of a part, without the extension.
+fn input_name(opt: &Opt, solution: &dyn DynSolution, part: Part) -> String {
+    if let Some(path) = &opt.input {
+        return match path.file_stem() {
+            Some(stem) if path != Path::new(STDIN) => stem.to_string_lossy().into_owned(),
+            _ => "stdin".to_owned(),
+        };
+    }
+
+    match (&opt.alt, solution.example_input(part)) {
+        _ if opt.real => format!("day{}", solution.day()),
+        (Some(alt), _) => alt.clone(),
+        (None, Some(example)) => example.to_owned(),
+        (None, None) => format!("day{}", solution.day()),
+    }
+}
+
+/// Reads all of stdin the first time, so that every day run by one command gets the same input.
+fn read_stdin() -> Result<String, InputError> {
+    static STDIN_INPUT: OnceLock<String> = OnceLock::new();
+    if let Some(input) = STDIN_INPUT.get() {
+        return Ok(input.clone());
+    }
+
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(STDIN_INPUT.get_or_init(|| input).clone())
+}
+
+/// The directory containing the inputs and answers of a year.
+pub fn inputs_dir(year: u16) -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("inputs");
+    path.push(year.to_string());
+    path
+}
+
+fn make_path(year: u16, name: &str, opt: &Opt) -> PathBuf {
+    let mut path = inputs_dir(year);
+
+    path.push(if opt.real { "real" } else { "example" });
+    path.push(name);
+    path.set_extension("txt");
+
+    path
+}
+
+/// Downloads the input and saves it, creating the directory if needed. The saved input acts as the
+/// cache of the download, so an input is only ever downloaded once.
+/// Nothing is saved when the download fails, so an error is never mistaken for an input.
+fn download_and_save(path: &Path, year: u16, day: u8) -> Result<String, InputError> {
+    let resp = download_input(year, day)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(InputError::io(dir))?;
+    }
+    fs::write(path, resp.as_bytes()).map_err(InputError::io(path))?;
+
+    Ok(resp)
+}
+
+fn download_input(year: u16, day: u8) -> Result<String, InputError> {
+    fetch_input(BASE_URL, &get_session_token()?, year, day)
+}
+
+/// Fetches an input from the site at `base_url`, which is normally [`BASE_URL`].
+pub fn fetch_input(
+    base_url: &str,
+    session: &str,
+    year: u16,
+    day: u8,
+) -> Result<String, InputError> {
+    let resp = client::get(&make_url(base_url, year, day), session)?;
+
+    let status = resp.status();
+    let body = resp.text()?;
+    check_input(status, &body, year, day)?;
+
+    Ok(body)
+}
+
+/// Checks that a response actually contains a puzzle input.
+fn check_input(status: StatusCode, body: &str, year: u16, day: u8) -> Result<(), InputError> {
+    match status {
+        StatusCode::OK => {}
+        StatusCode::NOT_FOUND => return Err(InputError::NotUnlocked { year, day }),
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            return Err(InputError::SessionRejected)
+        }
+        // an invalid session token sometimes results in a server error instead
+        _ if body.contains("log in") => return Err(InputError::SessionRejected),
+        _ => return Err(InputError::Status(status)),
+    }
+
+    let start = body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err(InputError::Html);
+    }
+    if body.trim().is_empty() {
+        return Err(InputError::Empty);
+    }
+
+    Ok(())
+}
+
+fn make_url(base_url: &str, year: u16, day: u8) -> String {
+    format!("{base_url}/{year}/day/{day}/input")
+}
@@ -0,0 +1,214 @@
+//! Viewing a private leaderboard, from the JSON the site provides for it.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use crate::client::{self, Cache};
+
+/// The site asks that private leaderboards are fetched at most once every 15 minutes.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    /// When each part of each day was solved, as a unix timestamp.
+    pub days: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+impl Member {
+    /// The name shown on the site, which is the anonymous id for members without a name.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// How long it took to solve each part of a day after it was unlocked.
+    pub fn solve_times(&self, year: u16, day: u8) -> [Option<Duration>; 2] {
+        let unlocked = unlock_time(year, day);
+        let parts = self.days.get(&day).copied().unwrap_or_default();
+        parts.map(|ts| ts.map(|ts| Duration::from_secs(ts.saturating_sub(unlocked))))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    /// Ranked by local score, then by stars.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(text: &str) -> Result<Self> {
+        let json: Value = serde_json::from_str(text)?;
+
+        let year = json
+            .get("event")
+            .and_then(|event| event.as_str()?.parse().ok())
+            .context("the leaderboard should have an `event` year")?;
+        let members = json
+            .get("members")
+            .and_then(Value::as_object)
+            .context("the leaderboard should have `members`")?;
+
+        let mut members = members
+            .values()
+            .map(|member| parse_member(member).context("invalid member in the leaderboard"))
+            .collect::<Result<Vec<_>>>()?;
+        members.sort_by(|a, b| {
+            (b.local_score, b.stars)
+                .cmp(&(a.local_score, a.stars))
+                .then_with(|| a.display_name().cmp(&b.display_name()))
+        });
+
+        Ok(Leaderboard { year, members })
+    }
+
+    /// Every day that at least one member has solved a part of.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self
+            .members
+            .iter()
+            .flat_map(|member| member.days.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Renders the ranking, followed by a table of the solve times of each day.
+    pub fn render(&self) -> String {
+        let width = self
+            .members
+            .iter()
+            .map(|member| member.display_name().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Name".len());
+
+        let mut out = String::new();
+        writeln!(out, "{:>4}  {:>5}  {:>5}  Name", "Rank", "Score", "Stars").unwrap();
+        for (rank, member) in self.members.iter().enumerate() {
+            writeln!(
+                out,
+                "{:>4}  {:>5}  {:>5}  {}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.display_name()
+            )
+            .unwrap();
+        }
+
+        for day in self.days() {
+            writeln!(
+                out,
+                "\n{:<width$}  {:>11}  {:>11}  {:>11}",
+                format!("Day {day}"),
+                "Part 1",
+                "Part 2",
+                "Delta"
+            )
+            .unwrap();
+
+            for member in &self.members {
+                let [part1, part2] = member.solve_times(self.year, day);
+                if part1.is_none() {
+                    continue;
+                }
+                let delta = part1.zip(part2).map(|(p1, p2)| p2.saturating_sub(p1));
+                writeln!(
+                    out,
+                    "{:<width$}  {:>11}  {:>11}  {:>11}",
+                    member.display_name(),
+                    format_time(part1),
+                    format_time(part2),
+                    format_time(delta)
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+}
+
+fn parse_member(json: &Value) -> Option<Member> {
+    let days = json
+        .get("completion_day_level")?
+        .as_object()?
+        .iter()
+        .map(|(day, parts)| {
+            let solved = |part: &str| parts.get(part)?.get("get_star_ts")?.as_u64();
+            Some((day.parse().ok()?, [solved("1"), solved("2")]))
+        })
+        .collect::<Option<_>>()?;
+
+    Some(Member {
+        id: json.get("id")?.as_u64()?,
+        name: json.get("name")?.as_str().map(String::from),
+        local_score: json.get("local_score")?.as_u64()?,
+        stars: json.get("stars")?.as_u64()?,
+        days,
+    })
+}
+
+/// Fetches the JSON of a private leaderboard from the site at `base_url`, which is normally
+/// [`crate::BASE_URL`]. Responses are cached for [`REFRESH_INTERVAL`], but only when they are a
+/// leaderboard, since the site answers with an HTML page when the session token has expired.
+pub fn fetch(cache: &Cache, base_url: &str, session: &str, year: u16, id: u64) -> Result<String> {
+    let url = format!("{base_url}/{year}/leaderboard/private/view/{id}.json");
+    if let Some(json) = cache.get(&url, Some(REFRESH_INTERVAL)) {
+        return Ok(json);
+    }
+
+    let json = client::get(&url, session)?.error_for_status()?.text()?;
+    Leaderboard::parse(&json)
+        .context("The response isn't a leaderboard, is the session token still valid?")?;
+    cache
+        .put(&url, &json)
+        .context("Couldn't cache the leaderboard")?;
+
+    Ok(json)
+}
+
+/// Puzzles unlock at midnight EST (UTC-5).
+fn unlock_time(year: u16, day: u8) -> u64 {
+    days_since_epoch(year.into(), 12, day.into()) * 24 * 60 * 60 + 5 * 60 * 60
+}
+
+/// The number of days between 1970-01-01 and a date, for dates after 1970.
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    // shift the year to start in March, so that the leap day is the last day of the year
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Formats a solve time like `00:12:34`, or like `1d 02h` when it took more than a day.
+fn format_time(time: Option<Duration>) -> String {
+    let Some(time) = time else {
+        return "-".to_owned();
+    };
+
+    let secs = time.as_secs();
+    match secs / 3600 {
+        hours if hours >= 24 => format!("{}d {:02}h", hours / 24, hours % 24),
+        hours => format!("{hours:02}:{:02}:{:02}", secs / 60 % 60, secs % 60),
+    }
+}
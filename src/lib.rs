@@ -1,125 +1,565 @@
-use clap::Parser;
-use std::env::VarError;
+use clap::{Parser, ValueEnum};
+use std::any::Any;
+use std::fmt;
+use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
-use std::{env, fs};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
-use reqwest::header::COOKIE;
+use anyhow::{bail, Context, Result};
 
-#[derive(Parser)]
-struct Opt {
-    #[arg(short, long)]
-    real: bool,
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod days;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod manifest;
+pub mod memory;
+pub mod puzzle;
+pub mod report;
+pub mod scaffold;
+pub mod session;
+pub mod status;
+pub mod submit;
 
-    #[arg(short, long)]
-    alt: Option<String>,
+use cancel::with_timeout;
+pub use cancel::{cancellation_token, cancelled};
+pub use input::inputs_dir;
+use input::{get_input, input_groups};
+use manifest::Manifest;
+use report::{print_header, DayReport, Format, PartReport};
+pub use session::get_session_token;
+
+/// A solution to a single day's puzzle.
+///
+/// The input is parsed once using [`Solution::parse`], after which both parts borrow the parsed
+/// model. Implementations are registered in [`days::registry`], which allows tooling to discover
+/// and drive solutions without having to know about each day individually.
+pub trait Solution: Sync {
+    /// The model that the input is parsed into, shared between both parts. Parts can be run on
+    /// another thread when they have a timeout, so it has to be shareable between threads.
+    type Parsed: Send + Sync + 'static;
+
+    /// The day of the puzzle, from 1 to 25.
+    fn day(&self) -> u8;
+
+    /// The year of the puzzle, which determines where its inputs are stored and downloaded from.
+    fn year(&self) -> u16 {
+        YEAR
+    }
+
+    /// The title of the puzzle, if known.
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The name of the example input of a part, for puzzles whose parts have different examples.
+    /// Defaults to `dayN`.
+    fn example_input(&self, part: Part) -> Option<&'static str> {
+        let _ = part;
+        None
+    }
+
+    /// The parameters of the puzzle that can be changed using `--param name=value`.
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn parse(&self, input: &str) -> Self::Parsed;
+
+    fn part1(&self, input: &Self::Parsed) -> Answer;
+
+    fn part2(&self, input: &Self::Parsed) -> Answer;
 }
 
-pub fn is_real() -> bool {
-    let opt = Opt::parse();
-    opt.real
+/// An object safe version of [`Solution`], which hides the parsed model behind [`Any`].
+///
+/// This is implemented for every [`Solution`], and is what the registry and runner work with.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn year(&self) -> u16;
+
+    fn title(&self) -> Option<&'static str>;
+
+    fn example_input(&self, part: Part) -> Option<&'static str>;
+
+    fn params(&self) -> &'static [Param];
+
+    fn parse(&self, input: &str) -> Arc<dyn Any + Send + Sync>;
+
+    /// Solves the given part of the puzzle using a model returned by [`DynSolution::parse`].
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Answer;
 }
 
-pub fn runner(f: impl Fn(&str)) {
-    let opt = Opt::parse();
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn year(&self) -> u16 {
+        Solution::year(self)
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Solution::title(self)
+    }
+
+    fn example_input(&self, part: Part) -> Option<&'static str> {
+        Solution::example_input(self, part)
+    }
 
-    let input = get_input(&opt);
+    fn params(&self) -> &'static [Param] {
+        Solution::params(self)
+    }
+
+    fn parse(&self, input: &str) -> Arc<dyn Any + Send + Sync> {
+        Arc::new(Solution::parse(self, input))
+    }
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("The parsed input should come from the same solution");
 
-    println!("---");
-    let start = Instant::now();
-    f(&input);
-    let duration = start.elapsed();
-    println!("--- {duration:?}")
+        match part {
+            Part::One => self.part1(parsed),
+            Part::Two => self.part2(parsed),
+        }
+    }
 }
 
-pub fn runner100(f: impl Fn(&str)) {
-    let opt = Opt::parse();
+/// The answer to a single part of a puzzle.
+///
+/// Integers of any width convert into an answer using `into()`, so parts can return whatever type
+/// is most natural to them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
 
-    let input = get_input(&opt);
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
 
-    println!("---");
-    let min_duration = (0..100)
-        .map(|_| {
-            let start = Instant::now();
-            f(&input);
-            start.elapsed()
-        })
-        .min()
-        .unwrap();
+impl_answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
 
-    println!("--- {min_duration:?}")
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+    }
 }
 
-/// This function panics when something goes wrong. That is intended behaviour.
-fn get_input(opt: &Opt) -> String {
-    let bin = binary_name();
-    let day = bin
-        .strip_prefix("day")
-        .and_then(|b| b.parse::<u8>().ok())
-        .unwrap();
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
 
-    let path = make_path(&bin, opt);
-    match (path.exists(), opt.real) {
-        (true, _) => fs::read_to_string(path).map_err(anyhow::Error::from),
-        (false, false) => panic!("Oh no! I couldn't find the example input file :("),
-        (false, true) => download_and_save(path, day),
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
     }
-    .unwrap()
 }
 
-fn binary_name() -> String {
-    env::args()
-        .next()
-        .as_ref()
-        .map(Path::new)
-        .and_then(Path::file_name)
-        .and_then(std::ffi::OsStr::to_str)
-        .map(String::from)
-        .expect("Couldn't find the binary name for some reason...")
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
 }
 
-fn make_path(bin_name: &str, opt: &Opt) -> PathBuf {
-    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
 
-    path.push("inputs");
-    path.push(if opt.real { "real" } else { "example" });
-    path.push(if opt.real || opt.alt.is_none() {
-        bin_name
-    } else {
-        opt.alt.as_ref().unwrap()
-    });
-    path.set_extension("txt");
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
 
-    path
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
 }
 
-/// This function may cause a path error when the "inputs/real" directory doesn't exist.
-/// The workaround is to create the directory manually.
-fn download_and_save(path: PathBuf, day: u8) -> Result<String> {
-    let resp = download_input(2023, day)?;
-    fs::write(path, resp.as_bytes())?;
-    Ok(resp)
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => anyhow::bail!("invalid part `{s}`, expected 1 or 2"),
+        }
+    }
 }
 
-fn download_input(year: u16, day: u8) -> Result<String> {
-    let client = reqwest::blocking::Client::new();
-    let resp = client
-        .get(make_url(year, day))
-        .header(
-            COOKIE,
-            String::from("session=") + get_session_token()?.as_str(),
-        )
-        .send()?
-        .text()?;
+/// The parts to run, as selected on the command line.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
 
-    Ok(resp)
+impl Parts {
+    pub fn parts(self) -> &'static [Part] {
+        match self {
+            Parts::One => &[Part::One],
+            Parts::Two => &[Part::Two],
+            Parts::Both => &Part::BOTH,
+        }
+    }
 }
 
-fn make_url(year: u16, day: u8) -> String {
-    format!("https://adventofcode.com/{year}/day/{day}/input")
+impl From<Part> for Parts {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Parts::One,
+            Part::Two => Parts::Two,
+        }
+    }
+}
+
+/// A constant of a puzzle that can be changed from the command line, e.g. to explore variants of
+/// the puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    /// The smallest value that the solution can handle.
+    pub min: u64,
+    /// The largest value that the solution can handle, e.g. without overflowing.
+    pub max: u64,
+    pub description: &'static str,
 }
 
-fn get_session_token() -> Result<String, VarError> {
-    env::var("AOC_SESSION")
+impl Param {
+    /// The value of the parameter in the current run, which is its default unless it was changed
+    /// on the command line.
+    pub fn get(&self) -> u64 {
+        PARAMS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|&&(name, _)| name == self.name)
+            .map_or(self.default, |&(_, value)| value)
+    }
+}
+
+/// Options shared by every way of running a solution.
+#[derive(Parser, Clone, Debug, Default)]
+pub struct Opt {
+    /// Use the real puzzle input instead of the example input
+    #[arg(short, long)]
+    pub real: bool,
+
+    /// The parts to run
+    #[arg(short, long, value_enum, default_value_t)]
+    pub part: Parts,
+
+    /// Use an alternate example input file for every part, e.g. `day10_complex`
+    #[arg(short, long)]
+    pub alt: Option<String>,
+
+    /// Read the input from a file anywhere, or from stdin when given `-`
+    #[arg(short, long, conflicts_with = "alt")]
+    pub input: Option<PathBuf>,
+
+    /// The year of the puzzles, defaults to the year of each solution
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Compare the answers against the manifest of known-correct answers
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Change a parameter of the puzzle, e.g. `--param unfold=3`. Answers aren't verified when a
+    /// parameter differs from its default
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, u64)>,
+
+    /// Print renderings of intermediate states to stderr, for the solutions that support it. These
+    /// are included in the timings
+    #[arg(long)]
+    pub visualize: bool,
+
+    /// Give up on a part after this many seconds, reporting it as timed out
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Report the peak heap usage and the allocations of each part, which makes them slightly slower.
+    /// Needs the `memory` feature
+    #[arg(long)]
+    pub memory: bool,
+
+    /// The format that results are written in
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl Opt {
+    /// The year to use for a solution, which can be overridden on the command line.
+    pub fn resolve_year(&self, solution: &dyn DynSolution) -> u16 {
+        self.year.unwrap_or_else(|| solution.year())
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, u64)> {
+    let (name, value) = s
+        .split_once('=')
+        .with_context(|| format!("invalid parameter `{s}`, expected NAME=VALUE"))?;
+    let value = value
+        .parse()
+        .with_context(|| format!("invalid value for the parameter `{name}`"))?;
+    Ok((name.to_owned(), value))
+}
+
+pub(crate) fn parse_seconds(s: &str) -> Result<Duration> {
+    let secs = s
+        .parse()
+        .with_context(|| format!("invalid number of seconds `{s}`"))?;
+    Duration::try_from_secs_f64(secs).with_context(|| format!("invalid number of seconds `{s}`"))
+}
+
+/// The Advent of Code website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The default year of solutions and commands that aren't tied to a solution.
+pub const YEAR: u16 = 2023;
+
+static REAL: AtomicBool = AtomicBool::new(false);
+
+/// Whether the solution currently being run is using the real puzzle input.
+pub fn is_real() -> bool {
+    REAL.load(Ordering::Relaxed)
+}
+
+static VISUALIZE: AtomicBool = AtomicBool::new(false);
+
+/// Whether the solution currently being run should print renderings of its intermediate states.
+/// These go to stderr, so that they don't mix with the results.
+pub fn visualize() -> bool {
+    VISUALIZE.load(Ordering::Relaxed)
+}
+
+/// The parameters that were changed for the solution currently being run.
+static PARAMS: RwLock<Vec<(&str, u64)>> = RwLock::new(Vec::new());
+
+/// Sets the parameters of a solution from the options, returning those that differ from their
+/// defaults.
+pub(crate) fn set_params(
+    solution: &dyn DynSolution,
+    opt: &Opt,
+) -> Result<Vec<(&'static str, u64)>> {
+    let declared = solution.params();
+    for (name, _) in &opt.params {
+        if !declared.iter().any(|p| p.name == name) {
+            if declared.is_empty() {
+                bail!(
+                    "day {} has no parameters, so `{name}` can't be changed",
+                    solution.day()
+                );
+            }
+            let params = declared
+                .iter()
+                .map(|p| {
+                    format!(
+                        "\n  {} (default {}, from {} to {}): {}",
+                        p.name, p.default, p.min, p.max, p.description
+                    )
+                })
+                .collect::<String>();
+            bail!(
+                "day {} has no parameter `{name}`, its parameters are:{params}",
+                solution.day()
+            );
+        }
+    }
+
+    let changed = declared
+        .iter()
+        .filter_map(|p| {
+            let &(_, value) = opt.params.iter().rev().find(|(name, _)| name == p.name)?;
+            (value != p.default).then_some((p, value))
+        })
+        .collect::<Vec<_>>();
+    if let Some((p, value)) = changed.iter().find(|(p, v)| !(p.min..=p.max).contains(v)) {
+        bail!(
+            "the parameter `{}` of day {} has to be from {} to {}, not {value}",
+            p.name,
+            solution.day(),
+            p.min,
+            p.max
+        );
+    }
+    let changed = changed
+        .into_iter()
+        .map(|(p, value)| (p.name, value))
+        .collect::<Vec<_>>();
+
+    *PARAMS.write().unwrap() = changed.clone();
+    Ok(changed)
+}
+
+/// Formats changed parameters like `factor=10, unfold=3`.
+pub(crate) fn format_params(params: &[(&str, u64)]) -> String {
+    params
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Writes `contents` to `path`, creating its directory first if needed.
+pub(crate) fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Couldn't create the directory {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("Couldn't write {}", path.display()))
+}
+
+/// Runs a solution, timing the parsing and each part.
+pub fn run(solution: &'static dyn DynSolution) {
+    let opt = Opt::parse();
+    if let Err(err) = run_with(solution, &opt) {
+        eprintln!("Error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+/// Runs the selected parts of a solution with the given options, timing the parsing and each part.
+///
+/// Returns a report for every input that was used, since parts can have different example inputs.
+pub fn run_with(solution: &'static dyn DynSolution, opt: &Opt) -> Result<Vec<DayReport>> {
+    REAL.store(opt.real, Ordering::Relaxed);
+    if opt.memory && !memory::AVAILABLE {
+        bail!("measuring memory needs the `memory` feature, e.g. `cargo run --features memory`");
+    }
+    VISUALIZE.store(opt.visualize, Ordering::Relaxed);
+    let changed = set_params(solution, opt)?;
+    let text = opt.format == Format::Text;
+    let year = opt.resolve_year(solution);
+
+    // the manifest only knows the answers of its own inputs, for the default parameters
+    let manifest = if opt.verify && changed.is_empty() && opt.input.is_none() {
+        Some(Manifest::load(year)?)
+    } else {
+        None
+    };
+
+    let groups = input_groups(opt, solution);
+    if text {
+        print_header(solution.day(), solution.title());
+        if !changed.is_empty() {
+            println!("--- with {}", format_params(&changed));
+        }
+    }
+
+    let mut reports = Vec::with_capacity(groups.len());
+    for (name, parts) in groups {
+        let input = get_input(opt, year, solution.day(), &name)?;
+
+        if text {
+            println!("---");
+        }
+        let start = Instant::now();
+        let parsed = solution.parse(&input);
+        let parse_duration = start.elapsed();
+        if text {
+            println!("--- parsed {name} in {parse_duration:?}");
+        }
+
+        let mut part_reports = Vec::with_capacity(parts.len());
+        for part in parts {
+            if text {
+                println!("---");
+            }
+            let solve = {
+                let parsed = Arc::clone(&parsed);
+                || with_timeout(opt.timeout, move || solution.solve(part, parsed.as_ref()))
+            };
+            let start = Instant::now();
+            let (answer, memory) = if opt.memory {
+                let (answer, memory) = memory::measure(solve);
+                (answer, Some(memory))
+            } else {
+                (solve(), None)
+            };
+            let duration = start.elapsed();
+
+            let verdict = manifest
+                .as_ref()
+                .zip(answer.as_ref())
+                .map(|(manifest, answer)| manifest.verify(opt.real, &name, part, answer));
+            if text {
+                match (&answer, &verdict) {
+                    (None, _) => println!("Day {} Part {part}: timed out", solution.day()),
+                    (Some(answer), Some(verdict)) => {
+                        println!("Day {} Part {part}: {answer} [{verdict}]", solution.day())
+                    }
+                    (Some(answer), None) => {
+                        println!("Day {} Part {part}: {answer}", solution.day())
+                    }
+                }
+                println!("--- {duration:?}");
+                if let Some(memory) = &memory {
+                    println!("--- {memory}");
+                }
+            }
+
+            part_reports.push(PartReport {
+                part,
+                answer,
+                duration,
+                verdict,
+                memory,
+            });
+        }
+
+        let report = DayReport {
+            year,
+            day: solution.day(),
+            title: solution.title(),
+            input: name,
+            real: opt.real,
+            parse_duration,
+            parts: part_reports,
+        };
+        if !text {
+            println!("{}", report.to_json());
+        }
+        reports.push(report);
+    }
+
+    Ok(reports)
 }
@@ -1,4 +1,425 @@
-fn main() {
-    println!("Hi, I do nothing right now.");
-    println!("Please run `cargo run --bin <day>` to run a specific day.");
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use adventofcode_2023::bench::{bench_with, BenchConfig};
+use adventofcode_2023::client::Cache;
+use adventofcode_2023::history::{self, Entry};
+use adventofcode_2023::input::real_input;
+use adventofcode_2023::leaderboard::{self, Leaderboard};
+use adventofcode_2023::manifest::{hash_answer, Verdict};
+use adventofcode_2023::puzzle;
+use adventofcode_2023::report::{input_kind, print_header, Format};
+use adventofcode_2023::session::{self, SessionStatus, Sources};
+use adventofcode_2023::submit::submit;
+use adventofcode_2023::{
+    days, get_session_token, inputs_dir, run_with, Answer, DynSolution, Opt, Part, BASE_URL, YEAR,
+};
+use adventofcode_2023::{scaffold, status};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(version, about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one or more days
+    Run {
+        /// A single day (`5`), a range of days (`3..7`) or `all`
+        days: Days,
+
+        /// Only run this part (1 or 2), the same as `--part`
+        #[arg(id = "single_part", value_name = "PART", conflicts_with = "part")]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        opt: Opt,
+    },
+    /// Benchmark the solutions for one or more days
+    Bench {
+        /// A single day (`5`), a range of days (`3..7`) or `all`
+        days: Days,
+
+        /// Only benchmark this part (1 or 2), the same as `--part`
+        #[arg(id = "single_part", value_name = "PART", conflicts_with = "part")]
+        part: Option<Part>,
+
+        #[command(flatten)]
+        config: BenchConfig,
+
+        /// Don't append the results to the benchmark history, which is also skipped when
+        /// parameters are changed
+        #[arg(long)]
+        no_save: bool,
+
+        #[command(flatten)]
+        opt: Opt,
+    },
+    /// Compare the latest benchmark results against a baseline from the benchmark history
+    Compare {
+        /// A single day (`5`), a range of days (`3..7`) or `all`
+        #[arg(default_value = "all")]
+        days: Days,
+
+        /// The commit to compare against, defaults to the latest results from a different commit
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Flag parts whose median got slower by more than this percentage
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+    },
+    /// Solve a part using the real input and submit the answer
+    Submit {
+        day: u8,
+
+        /// The part to submit (1 or 2)
+        part: Part,
+
+        /// The year of the puzzle, defaults to the year of the solution
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Create the solution, binary and example input of a new day
+    New {
+        day: u8,
+
+        /// The year of the puzzle
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+
+        /// Also download the real input, and the puzzle with its examples
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Show which days have solutions, inputs, verified answers and benchmark timings
+    Status {
+        /// The year to show
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Print the hash of an answer, for storing real answers in the manifest without spoiling them
+    Hash { answer: String },
+    /// Download a puzzle description, and extract its example inputs and answers
+    FetchPuzzle {
+        day: u8,
+
+        /// The year of the puzzle
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Show a private leaderboard, with the solve times of each member
+    Leaderboard {
+        /// The id of the leaderboard, which is the number in its URL
+        #[arg(required_unless_present = "file")]
+        id: Option<u64>,
+
+        /// Read the leaderboard JSON from a file instead of fetching it
+        #[arg(long, conflicts_with = "id")]
+        file: Option<PathBuf>,
+
+        /// The year of the leaderboard
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Manage the session token used to download inputs and submit answers
+    #[command(subcommand)]
+    Auth(Auth),
+}
+
+#[derive(Subcommand)]
+enum Auth {
+    /// Check that the session token is found and accepted by the site
+    Check,
+    /// Save a session token to a file that only you can read
+    Save { token: String },
+}
+
+/// A selection of days given on the command line.
+#[derive(Clone)]
+enum Days {
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+impl Days {
+    fn contains(&self, day: u8) -> bool {
+        match self {
+            Days::All => true,
+            Days::Range(range) => range.contains(&day),
+        }
+    }
+
+    fn solutions(&self) -> Result<Vec<&'static dyn DynSolution>> {
+        let solutions = days::registry()
+            .iter()
+            .filter(|s| self.contains(s.day()))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if solutions.is_empty() {
+            bail!("no solutions found for the selected days");
+        }
+
+        Ok(solutions)
+    }
+}
+
+impl FromStr for Days {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+
+        let (start, end) = s
+            .split_once("..")
+            .or_else(|| s.split_once('-'))
+            .unwrap_or((s, s));
+        let (start, end) = (start.parse::<u8>()?, end.parse::<u8>()?);
+        if start == 0 || end > 25 || start > end {
+            bail!("invalid day selection `{s}`, days range from 1 to 25");
+        }
+
+        Ok(Days::Range(start..=end))
+    }
+}
+
+fn compare(days: &Days, baseline: Option<&str>, threshold: f64) -> Result<()> {
+    let entries = history::load()?
+        .into_iter()
+        .filter(|entry| days.contains(entry.day))
+        .collect::<Vec<_>>();
+    let comparisons = history::compare(&entries, baseline, threshold / 100.0);
+    if comparisons.is_empty() {
+        println!("Nothing to compare, benchmark the same parts at another commit first");
+        return Ok(());
+    }
+
+    let commit = |entry: &Entry| entry.commit.clone().unwrap_or_else(|| "?".to_owned());
+    for c in &comparisons {
+        println!(
+            "Day {:>2} Part {} ({}): {:?} @ {} -> {:?} @ {} ({:+.1}%){}",
+            c.current.day,
+            c.current.part,
+            c.current.input,
+            c.baseline.median,
+            commit(&c.baseline),
+            c.current.median,
+            commit(&c.current),
+            c.change * 100.0,
+            if c.regressed { " REGRESSED" } else { "" }
+        );
+    }
+
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 {
+        bail!("{regressed} part(s) got more than {threshold}% slower");
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            mut opt,
+        } => {
+            if let Some(part) = part {
+                opt.part = part.into();
+            }
+            let (mut verdicts, mut timed_out) = (Vec::new(), 0);
+            for solution in days.solutions()? {
+                for report in run_with(solution, &opt)? {
+                    timed_out += report.parts.iter().filter(|r| r.answer.is_none()).count();
+                    verdicts.extend(report.parts.into_iter().filter_map(|r| r.verdict));
+                }
+            }
+
+            if opt.verify {
+                let count = |v: &Verdict| verdicts.iter().filter(|&x| x == v).count();
+                let failed = verdicts.len() - count(&Verdict::Pass) - count(&Verdict::Missing);
+                if opt.format == Format::Text {
+                    println!(
+                        "=== {} passed, {failed} failed, {} missing",
+                        count(&Verdict::Pass),
+                        count(&Verdict::Missing)
+                    );
+                }
+                if failed > 0 {
+                    bail!("{failed} answer(s) didn't match the manifest");
+                }
+            }
+            if timed_out > 0 {
+                bail!("{timed_out} part(s) timed out");
+            }
+        }
+        Command::Bench {
+            days,
+            part,
+            config,
+            no_save,
+            mut opt,
+        } => {
+            if let Some(part) = part {
+                opt.part = part.into();
+            }
+            // the history is only comparable for the default parameters, and for the inputs in
+            // `inputs/<year>`, since any other input is only known by its file name
+            let save = !no_save && opt.params.is_empty() && opt.input.is_none();
+            let mut timed_out = 0;
+            for solution in days.solutions()? {
+                for report in bench_with(solution, &opt, &config)? {
+                    timed_out += report.parts.iter().filter(|(_, s)| s.is_none()).count();
+                    if save {
+                        let entries = report
+                            .parts
+                            .iter()
+                            .filter_map(|(part, stats)| Some((part, stats.as_ref()?)))
+                            .map(|(part, stats)| {
+                                let kind = input_kind(report.real);
+                                Entry::new(
+                                    report.year,
+                                    report.day,
+                                    *part,
+                                    &report.input,
+                                    kind,
+                                    stats,
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        history::append(&entries)?;
+                    }
+                }
+            }
+            if timed_out > 0 {
+                bail!("{timed_out} part(s) timed out");
+            }
+        }
+        Command::Compare {
+            days,
+            baseline,
+            threshold,
+        } => compare(&days, baseline.as_deref(), threshold)?,
+        Command::Submit { day, part, year } => {
+            let Some(solution) = days::find(day) else {
+                bail!("no solution found for day {day}");
+            };
+            let opt = Opt {
+                real: true,
+                year,
+                part: part.into(),
+                ..Opt::default()
+            };
+            let report = run_with(solution, &opt)?.remove(0);
+            let answer = report.parts[0]
+                .answer
+                .as_ref()
+                .expect("parts only time out when given a timeout");
+
+            println!("Submitting {answer} for day {day} part {part}...");
+            let session = get_session_token()?;
+            let submission = submit(BASE_URL, &session, report.year, day, part, answer)?;
+            println!("{submission}");
+        }
+        Command::New { day, year, fetch } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            scaffold::check_new(root, day)?;
+
+            let mut title = None;
+            if fetch {
+                let session = get_session_token()?;
+                let puzzle = puzzle::fetch(&Cache::standard(), BASE_URL, &session, year, day)?;
+                let saved = puzzle::save(&puzzle, &inputs_dir(year))?;
+                println!("Saved the description to {}", saved.description.display());
+                for path in &saved.examples {
+                    println!("Saved an example input to {}", path.display());
+                }
+                real_input(year, day)?;
+                println!("Downloaded the real input");
+                title = puzzle.title;
+            }
+
+            for path in scaffold::create(root, year, day, title.as_deref())? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Status { year } => {
+            let days = status::collect(year, &inputs_dir(year), &history::load()?)?;
+            print!("{}", status::render(year, &days));
+        }
+        Command::Hash { answer } => println!("{}", hash_answer(&Answer::from(answer))),
+        Command::FetchPuzzle { day, year } => {
+            let puzzle = puzzle::fetch(
+                &Cache::standard(),
+                BASE_URL,
+                &get_session_token()?,
+                year,
+                day,
+            )?;
+            let saved = puzzle::save(&puzzle, &inputs_dir(year))?;
+
+            print_header(day, puzzle.title.as_deref());
+            println!("Saved the description to {}", saved.description.display());
+            for path in &saved.examples {
+                println!("Saved an example input to {}", path.display());
+            }
+            for path in &saved.skipped {
+                println!("Kept the existing example input {}", path.display());
+            }
+            for (name, part, answer) in &saved.answers {
+                println!("Recorded {answer} as the answer of {name} part {part}");
+            }
+            if !puzzle.is_complete() {
+                println!(
+                    "Part 2 isn't available yet, fetch the puzzle again once part 1 is solved"
+                );
+            }
+        }
+        Command::Leaderboard { id, file, year } => {
+            let json = match (file, id) {
+                (Some(file), _) => fs::read_to_string(&file)
+                    .with_context(|| format!("Couldn't read {}", file.display()))?,
+                (None, Some(id)) => leaderboard::fetch(
+                    &Cache::standard(),
+                    BASE_URL,
+                    &get_session_token()?,
+                    year,
+                    id,
+                )?,
+                (None, None) => unreachable!("clap requires either an id or a file"),
+            };
+            print!("{}", Leaderboard::parse(&json)?.render());
+        }
+        Command::Auth(Auth::Check) => {
+            let (token, source) = Sources::standard().resolve()?;
+            println!("Found a session token in {source}");
+
+            let status = session::check(BASE_URL, &token, YEAR)?;
+            println!("{status}");
+            if status == SessionStatus::Expired {
+                bail!("the session token has expired");
+            }
+        }
+        Command::Auth(Auth::Save { token }) => {
+            let path = session::config_dir()
+                .context("couldn't find the config directory, set HOME or XDG_CONFIG_HOME")?
+                .join("session");
+            session::save_token(&path, &token)?;
+            println!("Saved the session token to {}", path.display());
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,196 @@
+//! The manifest of known-correct answers, stored in `inputs/<year>/answers.json`.
+//!
+//! Answers are grouped by input kind (`example` or `real`), then by input name (the file name
+//! without extension, e.g. `day10_complex`), then by part (`part1` or `part2`). An answer is either
+//! stored in plain text, or as `sha256:<hex digest>` so that real answers aren't spoiled.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use hashbrown::HashMap;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{inputs_dir, Answer, Part};
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// A single expected answer from the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    Hashed(String),
+}
+
+impl Expected {
+    fn parse(s: &str) -> Self {
+        match s.strip_prefix(HASH_PREFIX) {
+            Some(hash) => Expected::Hashed(hash.to_lowercase()),
+            None => Expected::Plain(s.to_owned()),
+        }
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        let answer = answer.to_string();
+        match self {
+            Expected::Plain(expected) => *expected == answer,
+            Expected::Hashed(hash) => *hash == sha256_hex(&answer),
+        }
+    }
+}
+
+/// The result of comparing a computed answer against the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(Expected),
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(Expected::Plain(expected)) => write!(f, "FAIL (expected {expected})"),
+            Verdict::Fail(Expected::Hashed(_)) => write!(f, "FAIL (expected a different hash)"),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Manifest {
+    example: HashMap<String, HashMap<Part, Expected>>,
+    real: HashMap<String, HashMap<Part, Expected>>,
+}
+
+impl Manifest {
+    /// Loads the manifest of a year, or an empty manifest if it doesn't exist.
+    pub fn load(year: u16) -> Result<Self> {
+        Manifest::load_from(&manifest_path(year))
+    }
+
+    /// Loads the manifest at `path`, or an empty manifest if it doesn't exist.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read the manifest at {}", path.display()))?;
+        Manifest::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let json: Value = serde_json::from_str(text)?;
+
+        Ok(Manifest {
+            example: parse_kind(&json, "example")?,
+            real: parse_kind(&json, "real")?,
+        })
+    }
+
+    pub fn expected(&self, real: bool, input: &str, part: Part) -> Option<&Expected> {
+        let answers = if real { &self.real } else { &self.example };
+        answers.get(input).and_then(|parts| parts.get(&part))
+    }
+
+    pub fn verify(&self, real: bool, input: &str, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(real, input, part) {
+            Some(expected) if expected.matches(answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+fn parse_kind(json: &Value, kind: &str) -> Result<HashMap<String, HashMap<Part, Expected>>> {
+    let Some(inputs) = json.get(kind) else {
+        return Ok(HashMap::new());
+    };
+    let Some(inputs) = inputs.as_object() else {
+        bail!("`{kind}` in the manifest should be an object");
+    };
+
+    inputs
+        .iter()
+        .map(|(input, parts)| {
+            let Some(parts) = parts.as_object() else {
+                bail!("`{kind}.{input}` in the manifest should be an object");
+            };
+
+            let parts = parts
+                .iter()
+                .map(|(part, answer)| {
+                    let part = part
+                        .strip_prefix("part")
+                        .unwrap_or(part)
+                        .parse::<Part>()
+                        .with_context(|| format!("Invalid part in `{kind}.{input}`"))?;
+                    let answer = match answer {
+                        Value::String(s) => Expected::parse(s),
+                        Value::Number(n) => Expected::Plain(n.to_string()),
+                        _ => bail!("`{kind}.{input}.part{part}` should be a string or number"),
+                    };
+                    Ok((part, answer))
+                })
+                .collect::<Result<_>>()?;
+
+            Ok((input.clone(), parts))
+        })
+        .collect()
+}
+
+/// Adds an example answer to the manifest at `path`, unless the input already has an answer for
+/// the part. Returns whether the answer was added.
+pub fn record_example(path: &Path, input: &str, part: Part, answer: &str) -> Result<bool> {
+    let mut json = if path.exists() {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read the manifest at {}", path.display()))?;
+        serde_json::from_str(&text)?
+    } else {
+        Value::Object(Map::new())
+    };
+
+    let parts = json
+        .as_object_mut()
+        .context("the manifest should be an object")?
+        .entry("example")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .context("`example` in the manifest should be an object")?
+        .entry(input)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .with_context(|| format!("`example.{input}` in the manifest should be an object"))?;
+
+    let key = format!("part{part}");
+    if parts.contains_key(&key) {
+        return Ok(false);
+    }
+    parts.insert(key, Value::String(answer.to_owned()));
+
+    fs::write(path, serde_json::to_string_pretty(&json)? + "\n")
+        .with_context(|| format!("Couldn't write the manifest at {}", path.display()))?;
+    Ok(true)
+}
+
+pub fn manifest_path(year: u16) -> PathBuf {
+    let mut path = inputs_dir(year);
+    path.push("answers.json");
+    path
+}
+
+/// Hashes an answer the same way hashed answers are stored in the manifest.
+pub fn hash_answer(answer: &Answer) -> String {
+    format!("{HASH_PREFIX}{}", sha256_hex(&answer.to_string()))
+}
+
+fn sha256_hex(s: &str) -> String {
+    Sha256::digest(s.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
@@ -0,0 +1,148 @@
+//! Measuring the heap usage of parts, using a global allocator that counts allocations.
+//!
+//! The allocator is only installed when the `memory` feature is enabled, so that other builds and
+//! users of the library keep the system allocator, or can set their own.
+//!
+//! Counting is only switched on while a part is measured, so that other runs only pay for checking
+//! whether it is. Allocations are counted on every thread, which includes rayon's workers, but
+//! also any parts that are still running in the background after timing out.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+use serde_json::{json, Value};
+
+/// Whether the counting allocator is installed, without which nothing can be measured.
+pub const AVAILABLE: bool = cfg!(feature = "memory");
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// The bytes allocated since counting was first switched on, which goes negative when memory
+/// allocated before then is freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting allocations while [`measure`] is running.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record(&self, allocated: usize, freed: usize) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let change = allocated as isize - freed as isize;
+        let current = CURRENT.fetch_add(change, Ordering::Relaxed) + change;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        if allocated > 0 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED.fetch_add(allocated as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// The heap usage of a part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The most memory that was allocated at once, on top of what was allocated beforehand.
+    pub peak: usize,
+    /// The number of allocations, including reallocations.
+    pub allocations: u64,
+    /// The bytes allocated in total, which is more than the peak when memory is reused.
+    pub allocated: u64,
+}
+
+impl MemoryStats {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "peak_bytes": self.peak,
+            "allocations": self.allocations,
+            "allocated_bytes": self.allocated,
+        })
+    }
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} in {} allocation(s)",
+            format_bytes(self.peak as u64),
+            format_bytes(self.allocated),
+            self.allocations
+        )
+    }
+}
+
+/// Runs `f`, counting the allocations made on every thread while it runs. Nothing is counted
+/// unless the allocator is [`AVAILABLE`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryStats) {
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    ENABLED.store(true, Ordering::Relaxed);
+    let result = f();
+    ENABLED.store(false, Ordering::Relaxed);
+
+    let stats = MemoryStats {
+        peak: (PEAK.load(Ordering::Relaxed) - start).max(0) as usize,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+    };
+    (result, stats)
+}
+
+/// Formats a number of bytes using binary units, e.g. `12.3 KiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
@@ -0,0 +1,248 @@
+//! Fetching puzzle descriptions, and extracting their example inputs and answers.
+//!
+//! The description of each part is an `<article class="day-desc">` on the puzzle page, and the
+//! second part only appears once the first has been solved. Examples are the `<pre><code>` blocks
+//! of an article, and by convention the last emphasized code (`<code><em>`) is its example answer.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::client::{self, Cache};
+use crate::manifest;
+use crate::{write_file, Part};
+
+/// The description of a single part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Article {
+    pub part: Part,
+    pub markdown: String,
+    pub examples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub day: u8,
+    pub title: Option<String>,
+    pub articles: Vec<Article>,
+}
+
+impl Puzzle {
+    /// Parses a puzzle page. Only the first two articles are used.
+    pub fn parse(day: u8, html: &str) -> Self {
+        let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+            .unwrap()
+            .captures_iter(html)
+            .zip(Part::BOTH)
+            .map(|(cap, part)| Article::parse(part, &cap[1]))
+            .collect::<Vec<_>>();
+
+        let title = Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>")
+            .unwrap()
+            .captures(html)
+            .map(|cap| decode_entities(&cap[1]));
+
+        Puzzle {
+            day,
+            title,
+            articles,
+        }
+    }
+
+    /// Whether both parts are described, in which case the page won't change anymore.
+    pub fn is_complete(&self) -> bool {
+        self.articles.len() == Part::BOTH.len()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        self.articles
+            .iter()
+            .map(|article| article.markdown.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+            + "\n"
+    }
+
+    /// The names of the example inputs, paired with the examples. The examples of the first part
+    /// are named `dayN`, `dayN_2`, ... and those of the second part `dayN_part2`, `dayN_part2_2`...
+    pub fn named_examples(&self) -> Vec<(String, &str)> {
+        self.articles
+            .iter()
+            .flat_map(|article| {
+                let prefix = match article.part {
+                    Part::One => format!("day{}", self.day),
+                    Part::Two => format!("day{}_part2", self.day),
+                };
+                article
+                    .examples
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, example)| {
+                        let name = match i {
+                            0 => prefix.clone(),
+                            _ => format!("{prefix}_{}", i + 1),
+                        };
+                        (name, example.as_str())
+                    })
+            })
+            .collect()
+    }
+
+    /// The example answer of each part, paired with the name of the input it most likely belongs
+    /// to: the first example of the same part, or the first example of the puzzle otherwise.
+    pub fn named_answers(&self) -> Vec<(String, Part, &str)> {
+        let examples = self.named_examples();
+        self.articles
+            .iter()
+            .filter_map(|article| {
+                let answer = article.answer.as_deref()?;
+                let name = match article.part {
+                    Part::Two if !article.examples.is_empty() => format!("day{}_part2", self.day),
+                    _ => examples.first()?.0.clone(),
+                };
+                Some((name, article.part, answer))
+            })
+            .collect()
+    }
+}
+
+impl Article {
+    fn parse(part: Part, html: &str) -> Self {
+        let examples = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")
+            .unwrap()
+            .captures_iter(html)
+            .map(|cap| decode_entities(&strip_tags(&cap[1])))
+            .collect();
+
+        let answer = Regex::new(r"<code><em>(.*?)</em></code>")
+            .unwrap()
+            .captures_iter(html)
+            .last()
+            .map(|cap| decode_entities(&strip_tags(&cap[1])));
+
+        Article {
+            part,
+            markdown: to_markdown(html),
+            examples,
+            answer,
+        }
+    }
+}
+
+/// The files written for a puzzle, and the answers added to the manifest.
+#[derive(Clone, Debug, Default)]
+pub struct Saved {
+    pub description: PathBuf,
+    pub examples: Vec<PathBuf>,
+    /// Example inputs that already existed, and weren't overwritten.
+    pub skipped: Vec<PathBuf>,
+    pub answers: Vec<(String, Part, String)>,
+}
+
+/// Saves a puzzle into `dir`, which is normally [`crate::inputs_dir`]: the description to
+/// `puzzles/dayN.md`, the examples to `example/<name>.txt`, and the example answers to the
+/// manifest. Existing examples and answers are never overwritten.
+pub fn save(puzzle: &Puzzle, dir: &Path) -> Result<Saved> {
+    let mut saved = Saved {
+        description: dir.join("puzzles").join(format!("day{}.md", puzzle.day)),
+        ..Saved::default()
+    };
+    write_file(&saved.description, &puzzle.to_markdown())?;
+
+    for (name, example) in puzzle.named_examples() {
+        let path = dir.join("example").join(format!("{name}.txt"));
+        if path.exists() {
+            saved.skipped.push(path);
+        } else {
+            write_file(&path, example)?;
+            saved.examples.push(path);
+        }
+    }
+
+    let manifest = dir.join("answers.json");
+    for (name, part, answer) in puzzle.named_answers() {
+        if manifest::record_example(&manifest, &name, part, answer)? {
+            saved.answers.push((name, part, answer.to_owned()));
+        }
+    }
+
+    Ok(saved)
+}
+
+/// Fetches a puzzle page from the site at `base_url`, which is normally [`crate::BASE_URL`].
+///
+/// Pages describing both parts are cached, since they won't change anymore. Until then, the
+/// page is fetched every time so that the second part shows up once the first is solved.
+pub fn fetch(cache: &Cache, base_url: &str, session: &str, year: u16, day: u8) -> Result<Puzzle> {
+    let url = format!("{base_url}/{year}/day/{day}");
+    if let Some(html) = cache.get(&url, None) {
+        return Ok(Puzzle::parse(day, &html));
+    }
+
+    let html = client::get(&url, session)?.error_for_status()?.text()?;
+    let puzzle = Puzzle::parse(day, &html);
+    if puzzle.is_complete() {
+        cache
+            .put(&url, &html)
+            .context("Couldn't cache the puzzle page")?;
+    }
+
+    Ok(puzzle)
+}
+
+/// Converts the HTML of an article to Markdown. Only the elements used by puzzle descriptions are
+/// supported, and any other tags are dropped.
+fn to_markdown(html: &str) -> String {
+    // code blocks are set aside first, so that the other replacements leave their contents alone
+    let mut blocks = Vec::new();
+    let mut markdown = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")
+        .unwrap()
+        .replace_all(html, |cap: &regex::Captures| {
+            blocks.push(decode_entities(&strip_tags(&cap[1])));
+            format!("\u{0}{}\u{0}", blocks.len() - 1)
+        })
+        .into_owned();
+
+    let replacements = [
+        (r"<h2[^>]*>(.*?)</h2>", "## ${1}\n\n"),
+        (r"<code><em>(.*?)</em></code>", "**`${1}`**"),
+        (r"<em[^>]*>(.*?)</em>", "*${1}*"),
+        (r"<code>(.*?)</code>", "`${1}`"),
+        (r#"<a href="([^"]*)"[^>]*>(.*?)</a>"#, "[${2}](${1})"),
+        (r"<li>", "- "),
+        (r"</p>|</ul>", "\n"),
+    ];
+    for (pattern, replacement) in replacements {
+        markdown = Regex::new(pattern)
+            .unwrap()
+            .replace_all(&markdown, replacement)
+            .into_owned();
+    }
+
+    let mut markdown = decode_entities(&strip_tags(&markdown));
+    for (i, block) in blocks.iter().enumerate() {
+        markdown = markdown.replace(&format!("\u{0}{i}\u{0}"), &format!("\n```\n{block}```\n"));
+    }
+
+    Regex::new(r"\n{3,}")
+        .unwrap()
+        .replace_all(markdown.trim(), "\n\n")
+        .into_owned()
+}
+
+fn strip_tags(html: &str) -> String {
+    Regex::new(r"<[^>]*>")
+        .unwrap()
+        .replace_all(html, "")
+        .into_owned()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
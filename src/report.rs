@@ -0,0 +1,113 @@
+//! Reports of running solutions, and how they are written to stdout.
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use crate::manifest::Verdict;
+use crate::memory::MemoryStats;
+use crate::{Answer, Part};
+
+/// How results are written to stdout.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One JSON object per day, written as JSON Lines
+    Json,
+}
+
+/// The outcome of running a single part of a solution.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub part: Part,
+    /// Missing when the part timed out.
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    /// Only present when verifying answers against the manifest.
+    pub verdict: Option<Verdict>,
+    /// Only present when measuring memory.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartReport {
+    /// A short machine-readable status, which is `ok` unless the part timed out or the answer was
+    /// verified.
+    pub fn status(&self) -> &'static str {
+        if self.answer.is_none() {
+            return "timed_out";
+        }
+        match self.verdict {
+            None => "ok",
+            Some(Verdict::Pass) => "pass",
+            Some(Verdict::Fail(_)) => "fail",
+            Some(Verdict::Missing) => "missing",
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut json = json!({
+            "part": self.part.number(),
+            "answer": self.answer.as_ref().map(answer_to_json),
+            "solve_ns": self.duration.as_nanos() as u64,
+            "status": self.status(),
+        });
+        if let Some(memory) = &self.memory {
+            json["memory"] = memory.to_json();
+        }
+        json
+    }
+}
+
+/// The outcome of running a solution on a single input.
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<&'static str>,
+    /// The name of the input file, without the extension.
+    pub input: String,
+    pub real: bool,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "title": self.title,
+            "input": self.input,
+            "kind": input_kind(self.real),
+            "parse_ns": self.parse_duration.as_nanos() as u64,
+            "parts": self.parts.iter().map(PartReport::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Prints the header that precedes the text output of a day.
+pub fn print_header(day: u8, title: Option<&str>) {
+    match title {
+        Some(title) => println!("Day {day}: {title}"),
+        None => println!("Day {day}"),
+    }
+}
+
+pub fn input_kind(real: bool) -> &'static str {
+    if real {
+        "real"
+    } else {
+        "example"
+    }
+}
+
+/// Answers are written as JSON numbers where possible, so that they can be compared numerically.
+fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) => i64::try_from(*n).map_or_else(|_| json!(n.to_string()), |n| json!(n)),
+        Answer::Text(s) => json!(s),
+    }
+}
@@ -0,0 +1,235 @@
+//! Generating the files for a new day. The templates below are shown in `src/bin/README.md`,
+//! which has to be kept in sync with them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{write_file, YEAR};
+
+/// Creates the solution, its binary wrapper and an empty example input for a new day, and adds
+/// the solution to the registry. `root` is the root of the repository, normally
+/// `CARGO_MANIFEST_DIR`.
+///
+/// Nothing is written if the day already exists. Returns the paths that were created or changed.
+pub fn create(root: &Path, year: u16, day: u8, title: Option<&str>) -> Result<Vec<PathBuf>> {
+    check_new(root, day)?;
+
+    let [solution, wrapper] = source_paths(root, day);
+    let registry = root.join("src/days/mod.rs");
+    let examples = root.join("inputs").join(year.to_string()).join("example");
+    let example = examples.join(format!("day{day}.txt"));
+    // saved by `fetch-puzzle` when the second part has an example of its own
+    let part2_example = examples.join(format!("day{day}_part2.txt")).exists();
+
+    let mod_rs = fs::read_to_string(&registry)
+        .with_context(|| format!("Couldn't read the registry at {}", registry.display()))?;
+    let mod_rs = register(&mod_rs, day)?;
+
+    let mut created = Vec::new();
+    for (path, contents) in [
+        (
+            &solution,
+            solution_template(year, day, title, part2_example),
+        ),
+        (&wrapper, wrapper_template(day)),
+        (&registry, mod_rs),
+    ] {
+        write_file(path, &contents)?;
+        created.push(path.clone());
+    }
+    if !example.exists() {
+        write_file(&example, "")?;
+        created.push(example);
+    }
+
+    Ok(created)
+}
+
+/// Checks that a day can be created, because it is a valid day that doesn't exist yet.
+pub fn check_new(root: &Path, day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("day {day} isn't a day of Advent of Code");
+    }
+    for path in source_paths(root, day) {
+        if path.exists() {
+            bail!(
+                "{} already exists, refusing to overwrite it",
+                path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The paths of the solution and its binary wrapper.
+fn source_paths(root: &Path, day: u8) -> [PathBuf; 2] {
+    [
+        root.join("src/days").join(format!("day{day}.rs")),
+        root.join("src/bin").join(format!("day{day}.rs")),
+    ]
+}
+
+/// Adds a day to the source of `src/days/mod.rs`, keeping the modules sorted by name and the
+/// registry sorted by day.
+pub fn register(mod_rs: &str, day: u8) -> Result<String> {
+    let name = format!("day{day}");
+    let module = format!("pub mod {name};");
+    if mod_rs.lines().any(|line| line == module) {
+        bail!("day {day} is already registered");
+    }
+
+    let mut lines = mod_rs.lines().map(String::from).collect::<Vec<_>>();
+
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line.strip_prefix("pub mod ")?.strip_suffix(';')?)))
+        .collect::<Vec<_>>();
+    let Some(&(last_module, _)) = modules.last() else {
+        bail!("couldn't find the modules in the registry");
+    };
+    let at = modules
+        .iter()
+        .find(|&&(_, m)| m > name.as_str())
+        .map_or(last_module + 1, |&(i, _)| i);
+    lines.insert(at, module);
+
+    let entry_day = |line: &str| -> Option<u8> {
+        line.trim()
+            .strip_prefix("&day")?
+            .split("::")
+            .next()?
+            .parse()
+            .ok()
+    };
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, entry_day(line)?)))
+        .collect::<Vec<_>>();
+    let Some(&(last_entry, _)) = entries.last() else {
+        bail!("couldn't find the solutions in the registry");
+    };
+    let at = entries
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last_entry + 1, |&(i, _)| i);
+    lines.insert(at, format!("    &day{day}::Day{day},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+pub fn wrapper_template(day: u8) -> String {
+    format!(
+        "use adventofcode_2023::{{days::day{day}::Day{day}, run}};
+
+fn main() {{
+    run(&Day{day});
+}}
+"
+    )
+}
+
+/// The source of a new solution, which runs part 2 on `dayN_part2` if `part2_example` is set.
+pub fn solution_template(year: u16, day: u8, title: Option<&str>, part2_example: bool) -> String {
+    let mut methods = format!(
+        "    fn day(&self) -> u8 {{
+        {day}
+    }}
+"
+    );
+    if year != YEAR {
+        methods += &format!(
+            "
+    fn year(&self) -> u16 {{
+        {year}
+    }}
+"
+        );
+    }
+    if let Some(title) = title {
+        methods += &format!(
+            "
+    fn title(&self) -> Option<&'static str> {{
+        Some({title:?})
+    }}
+"
+        );
+    }
+    if part2_example {
+        methods += &format!(
+            "
+    fn example_input(&self, part: Part) -> Option<&'static str> {{
+        match part {{
+            Part::One => None,
+            Part::Two => Some(\"day{day}_part2\"),
+        }}
+    }}
+"
+        );
+    }
+    let imports = if part2_example {
+        "Answer, Part, Solution"
+    } else {
+        "Answer, Solution"
+    };
+
+    format!(
+        "use crate::{{{imports}}};
+
+fn parse_input(input: &str) -> Vec<String> {{
+    input.lines().map(String::from).collect()
+}}
+
+fn part1(input: &[String]) -> Answer {{
+    let _ = input;
+    Answer::from(\"unsolved\")
+}}
+
+fn part2(input: &[String]) -> Answer {{
+    let _ = input;
+    Answer::from(\"unsolved\")
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Parsed = Vec<String>;
+
+{methods}
+    fn parse(&self, input: &str) -> Self::Parsed {{
+        parse_input(input)
+    }}
+
+    fn part1(&self, input: &Self::Parsed) -> Answer {{
+        part1(input)
+    }}
+
+    fn part2(&self, input: &Self::Parsed) -> Answer {{
+        part2(input)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = \"\";
+
+    #[test]
+    #[ignore = \"add the example input and its answer\"]
+    fn part1_example() {{
+        assert_eq!(part1(&parse_input(EXAMPLE)), Answer::from(0));
+    }}
+
+    #[test]
+    #[ignore = \"add the example input and its answer\"]
+    fn part2_example() {{
+        assert_eq!(part2(&parse_input(EXAMPLE)), Answer::from(0));
+    }}
+}}
+"
+    )
+}
@@ -0,0 +1,247 @@
+//! Finding and checking the session cookie used to talk to Advent of Code.
+//!
+//! The token is looked up in order from:
+//! 1. the `AOC_SESSION` environment variable,
+//! 2. an `AOC_SESSION=<token>` line in the `.env` file at the root of the repository,
+//! 3. a `session=<token>` line in the user config file, `~/.config/adventofcode/config`,
+//! 4. the token file `~/.config/adventofcode/session`, which must only be accessible by its owner.
+//!
+//! The config directory respects `XDG_CONFIG_HOME`.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs};
+
+use regex::Regex;
+use reqwest::StatusCode;
+use thiserror::Error;
+
+use crate::client;
+
+const ENV_VAR: &str = "AOC_SESSION";
+
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("couldn't find a session token, set AOC_SESSION or save one using `auth save`")]
+    NotFound,
+
+    #[error(
+        "the token file {} can be accessed by other users (mode {mode:o}), restrict it using `chmod 600`",
+        path.display()
+    )]
+    Permissions { path: PathBuf, mode: u32 },
+
+    #[error("couldn't access {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Where a session token was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    DotEnv(PathBuf),
+    Config(PathBuf),
+    TokenFile(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Env => write!(f, "the {ENV_VAR} environment variable"),
+            Source::DotEnv(path) => write!(f, "{}", path.display()),
+            Source::Config(path) => write!(f, "{}", path.display()),
+            Source::TokenFile(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The places a session token is looked up, in order.
+#[derive(Clone, Debug, Default)]
+pub struct Sources {
+    pub env: Option<String>,
+    pub dotenv: Option<PathBuf>,
+    pub config: Option<PathBuf>,
+    pub token_file: Option<PathBuf>,
+}
+
+impl Sources {
+    /// The standard locations, as described in the module documentation.
+    pub fn standard() -> Self {
+        let config_dir = config_dir();
+        Sources {
+            env: env::var(ENV_VAR).ok(),
+            dotenv: Some(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env")),
+            config: config_dir.as_ref().map(|dir| dir.join("config")),
+            token_file: config_dir.map(|dir| dir.join("session")),
+        }
+    }
+
+    /// Finds the first token, along with where it was found.
+    pub fn resolve(&self) -> Result<(String, Source), SessionError> {
+        if let Some(token) = self.env.as_deref().and_then(non_empty) {
+            return Ok((token, Source::Env));
+        }
+        if let Some(path) = &self.dotenv {
+            if let Some(token) = read_key(path, ENV_VAR)? {
+                return Ok((token, Source::DotEnv(path.clone())));
+            }
+        }
+        if let Some(path) = &self.config {
+            if let Some(token) = read_key(path, "session")? {
+                return Ok((token, Source::Config(path.clone())));
+            }
+        }
+        if let Some(path) = &self.token_file {
+            if let Some(token) = read_token_file(path)? {
+                return Ok((token, Source::TokenFile(path.clone())));
+            }
+        }
+
+        Err(SessionError::NotFound)
+    }
+}
+
+pub fn get_session_token() -> Result<String, SessionError> {
+    Sources::standard().resolve().map(|(token, _)| token)
+}
+
+/// The per-user config directory, e.g. `~/.config/adventofcode`.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("adventofcode"))
+}
+
+/// Saves a token to a token file that only its owner can access.
+pub fn save_token(path: &Path, token: &str) -> Result<(), SessionError> {
+    let io_err = |source| SessionError::Io {
+        path: path.to_owned(),
+        source,
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_err)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path).map_err(io_err)?;
+    // the mode is only applied when creating the file, so tighten an existing file as well
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .map_err(io_err)?;
+    }
+    writeln!(file, "{}", token.trim()).map_err(io_err)
+}
+
+/// Reads the value of `key` from a file of `key=value` lines, ignoring comments and quotes.
+fn read_key(path: &Path, key: &str) -> Result<Option<String>, SessionError> {
+    let Some(text) = read_optional(path)? else {
+        return Ok(None);
+    };
+
+    Ok(text.lines().find_map(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (name, value) = line.split_once('=')?;
+        if name.trim() != key {
+            return None;
+        }
+        non_empty(value.trim().trim_matches(|c| c == '"' || c == '\''))
+    }))
+}
+
+fn read_token_file(path: &Path) -> Result<Option<String>, SessionError> {
+    #[cfg(unix)]
+    if let Ok(metadata) = fs::metadata(path) {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(SessionError::Permissions {
+                path: path.to_owned(),
+                mode,
+            });
+        }
+    }
+
+    Ok(read_optional(path)?.and_then(|text| non_empty(&text)))
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, SessionError> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(SessionError::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_owned())
+}
+
+/// Whether the site accepted a session token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionStatus {
+    /// The token is valid, and belongs to the named user.
+    Valid(String),
+    /// The site didn't recognise the token, most likely because it expired.
+    Expired,
+}
+
+impl fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionStatus::Valid(user) => {
+                write!(f, "The session token is valid, logged in as {user}.")
+            }
+            SessionStatus::Expired => write!(
+                f,
+                "The session token was rejected, log in again and update the token."
+            ),
+        }
+    }
+}
+
+/// Checks a token against the site at `base_url`, which is normally [`crate::BASE_URL`], by
+/// looking for the logged in user on the calendar page of `year`.
+pub fn check(base_url: &str, session: &str, year: u16) -> anyhow::Result<SessionStatus> {
+    let resp = client::get(&format!("{base_url}/{year}"), session)?;
+
+    match resp.status() {
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            return Ok(SessionStatus::Expired)
+        }
+        status if !status.is_success() => anyhow::bail!("the server responded with {status}"),
+        _ => {}
+    }
+
+    Ok(parse_user(&resp.text()?).map_or(SessionStatus::Expired, SessionStatus::Valid))
+}
+
+/// The name of the logged in user, from e.g. `<div class="user">name <span class="star-count">`.
+fn parse_user(html: &str) -> Option<String> {
+    let user = Regex::new(r#"<div class="user">([^<]*)"#)
+        .unwrap()
+        .captures(html)?
+        .get(1)?
+        .as_str()
+        .trim()
+        .to_owned();
+
+    Some(user).filter(|user| !user.is_empty())
+}
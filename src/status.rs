@@ -0,0 +1,140 @@
+//! An overview of the progress of a year, rendered as a calendar.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::days;
+use crate::history::Entry;
+use crate::manifest::Manifest;
+use crate::Part;
+
+/// The number of puzzles in a year.
+pub const DAYS: u8 = 25;
+
+/// Where a single day stands.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub solution: bool,
+    /// The number of example inputs, including alternate ones.
+    pub examples: usize,
+    pub real: bool,
+    /// The number of parts with a known-correct answer for the real input.
+    pub answers: usize,
+    /// The total median time of the latest benchmark of each part.
+    pub timing: Option<Duration>,
+}
+
+/// Collects the status of every day of `year`, whose inputs are in `dir` (normally
+/// [`crate::inputs_dir`]), using the benchmark history for the timings.
+pub fn collect(year: u16, dir: &Path, history: &[Entry]) -> Result<Vec<DayStatus>> {
+    let manifest = Manifest::load_from(&dir.join("answers.json"))?;
+    let examples = fs::read_dir(dir.join("example"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    Ok((1..=DAYS)
+        .map(|day| {
+            let name = format!("day{day}");
+            DayStatus {
+                day,
+                solution: days::find(day).is_some_and(|s| s.year() == year),
+                examples: examples
+                    .iter()
+                    .filter_map(|file| file.strip_suffix(".txt"))
+                    .filter(|file| {
+                        file.strip_prefix(&name)
+                            .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+                    })
+                    .count(),
+                real: dir.join("real").join(format!("{name}.txt")).exists(),
+                answers: Part::BOTH
+                    .iter()
+                    .filter(|&&part| manifest.expected(true, &name, part).is_some())
+                    .count(),
+                timing: latest_timing(history, year, day),
+            }
+        })
+        .collect())
+}
+
+/// The total median of the latest entry of each part, preferring the real input.
+fn latest_timing(history: &[Entry], year: u16, day: u8) -> Option<Duration> {
+    let latest = |part: Part| {
+        let entries = || {
+            history
+                .iter()
+                .rev()
+                .filter(|e| e.year == year && e.day == day && e.part == part)
+        };
+        entries()
+            .find(|e| e.kind == "real")
+            .or_else(|| entries().next())
+            .map(|e| e.median)
+    };
+
+    Part::BOTH
+        .iter()
+        .filter_map(|&part| latest(part))
+        .reduce(|a, b| a + b)
+}
+
+/// Renders the days as a calendar of five weeks, followed by a legend and totals.
+pub fn render(year: u16, days: &[DayStatus]) -> String {
+    let mut out = String::new();
+    writeln!(out, "Advent of Code {year}").unwrap();
+
+    for week in days.chunks(5) {
+        let cells = week.iter().map(render_day).collect::<Vec<_>>();
+        writeln!(out, "{}", cells.join(" | ").trim_end()).unwrap();
+    }
+
+    let count = |f: fn(&DayStatus) -> bool| days.iter().filter(|d| f(d)).count();
+    writeln!(
+        out,
+        "\nS: solution, E: example input, R: real input, *: verified real answer"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "{} solutions, {} with examples, {} with real inputs, {} verified stars",
+        count(|d| d.solution),
+        count(|d| d.examples > 0),
+        count(|d| d.real),
+        days.iter().map(|d| d.answers).sum::<usize>()
+    )
+    .unwrap();
+
+    out
+}
+
+fn render_day(status: &DayStatus) -> String {
+    let flag = |set: bool, c: char| if set { c } else { '.' };
+    format!(
+        "{:>2} {}{}{}{:<2} {:>7}",
+        status.day,
+        flag(status.solution, 'S'),
+        flag(status.examples > 0, 'E'),
+        flag(status.real, 'R'),
+        "*".repeat(status.answers),
+        status.timing.map_or_else(String::new, format_duration)
+    )
+}
+
+/// Formats a duration in at most 7 characters, e.g. `12.3ms`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    match secs {
+        s if s >= 1.0 => format!("{s:.1}s"),
+        s if s >= 1e-3 => format!("{:.1}ms", s * 1e3),
+        s => format!("{:.0}µs", s * 1e6),
+    }
+}
@@ -0,0 +1,108 @@
+//! Submitting answers to Advent of Code.
+
+use std::fmt;
+use std::time::Duration;
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::client;
+use crate::{Answer, Part};
+
+/// The outcome of submitting an answer, as parsed from the response page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, and another can be submitted after the wait time.
+    RateLimited(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    AlreadySolved,
+    /// The response couldn't be recognised, and contains the text of the response.
+    Unknown(String),
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer!"),
+            Submission::Wrong => write!(f, "That's not the right answer."),
+            Submission::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            Submission::TooLow => write!(f, "That's not the right answer, it's too low."),
+            Submission::RateLimited(wait) => {
+                write!(f, "You gave an answer too recently, wait {wait:?}.")
+            }
+            Submission::AlreadySolved => write!(f, "This part is already solved."),
+            Submission::Unknown(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Submits an answer to the site at `base_url`, which is normally [`crate::BASE_URL`].
+pub fn submit(
+    base_url: &str,
+    session: &str,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Submission> {
+    let resp = client::post_form(
+        &format!("{base_url}/{year}/day/{day}/answer"),
+        session,
+        &[
+            ("level", part.number().to_string()),
+            ("answer", answer.to_string()),
+        ],
+    )?
+    .error_for_status()?
+    .text()?;
+
+    Ok(parse_response(&resp))
+}
+
+/// Parses the page returned after submitting an answer.
+pub fn parse_response(html: &str) -> Submission {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Submission::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Submission::TooHigh
+        } else if text.contains("too low") {
+            Submission::TooLow
+        } else {
+            Submission::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Submission::RateLimited(parse_wait(&text).unwrap_or_default())
+    } else if text.contains("Did you already complete it?") {
+        Submission::AlreadySolved
+    } else {
+        Submission::Unknown(text)
+    }
+}
+
+/// The text content of the `<article>` element, or the whole page if there isn't one.
+fn article_text(html: &str) -> String {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>")
+        .unwrap()
+        .captures(html)
+        .map_or(html, |cap| cap.get(1).unwrap().as_str());
+
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(article, "");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the wait time from e.g. "You have 1m 3s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let cap = Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s )?left to wait")
+        .unwrap()
+        .captures(text)?;
+
+    let number = |i| cap.get(i).map_or(Ok(0), |m| m.as_str().parse::<u64>());
+    Some(Duration::from_secs(number(1).ok()? * 60 + number(2).ok()?))
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use adventofcode_2023::bench::{BenchConfig, Stats};
+use clap::Parser;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    config: BenchConfig,
+}
+
+#[test]
+fn parses_the_time_budget() {
+    let budget = |arg: &str| Cli::try_parse_from(["bench", arg]).map(|cli| cli.config.budget);
+
+    assert_eq!(budget("--budget=0.5").unwrap(), Duration::from_millis(500));
+    assert_eq!(
+        Cli::try_parse_from(["bench"]).unwrap().config.budget,
+        Duration::from_secs(10)
+    );
+    for invalid in [
+        "--budget=-1",
+        "--budget=inf",
+        "--budget=nan",
+        "--budget=ten",
+    ] {
+        assert!(budget(invalid).is_err(), "{invalid} should be rejected");
+    }
+}
+
+fn ms(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&ms| Duration::from_millis(ms))
+        .collect()
+}
+
+/// Asserts that a duration computed using floats is within a nanosecond of the expected one.
+fn assert_close(actual: Duration, expected_ms: f64) {
+    let expected = Duration::from_secs_f64(expected_ms / 1000.0);
+    assert!(
+        actual.abs_diff(expected) <= Duration::from_nanos(1),
+        "{actual:?} should be {expected:?}"
+    );
+}
+
+#[test]
+fn computes_statistics_of_an_odd_number_of_samples() {
+    let stats = Stats::from_samples(&ms(&[5, 1, 4, 2, 3]));
+
+    assert_eq!(stats.samples, 5);
+    assert_close(stats.mean, 3.0);
+    assert_eq!(stats.median, Duration::from_millis(3));
+    // the sample standard deviation, sqrt(10 / 4)
+    assert_close(stats.stddev, 2.5f64.sqrt());
+    // the nearest rank is ceil(0.95 * 5) = 5
+    assert_eq!(stats.p95, Duration::from_millis(5));
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.max, Duration::from_millis(5));
+}
+
+#[test]
+fn computes_statistics_of_an_even_number_of_samples() {
+    let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+
+    assert_close(stats.mean, 2.5);
+    // the mean of the middle two samples
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    // sqrt(5 / 3)
+    assert_close(stats.stddev, (5.0f64 / 3.0).sqrt());
+    assert_eq!(stats.p95, Duration::from_millis(4));
+}
+
+#[test]
+fn uses_the_nearest_rank_for_the_95th_percentile() {
+    let p95 = |n: u64| Stats::from_samples(&ms(&(1..=n).collect::<Vec<_>>())).p95;
+
+    // ceil(0.95 * 20) = 19, so the 19th smallest sample
+    assert_eq!(p95(20), Duration::from_millis(19));
+    // ceil(0.95 * 21) = 20
+    assert_eq!(p95(21), Duration::from_millis(20));
+    assert_eq!(p95(100), Duration::from_millis(95));
+    assert_eq!(p95(1), Duration::from_millis(1));
+}
+
+#[test]
+fn has_no_spread_for_a_single_sample() {
+    let stats = Stats::from_samples(&ms(&[7]));
+
+    assert_close(stats.mean, 7.0);
+    assert_eq!(stats.median, Duration::from_millis(7));
+    assert_eq!(stats.stddev, Duration::ZERO);
+    assert_eq!(stats.p95, Duration::from_millis(7));
+}
+
+#[test]
+#[should_panic(expected = "without samples")]
+fn needs_samples() {
+    Stats::from_samples(&[]);
+}
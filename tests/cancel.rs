@@ -0,0 +1,106 @@
+mod common;
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use adventofcode_2023::bench::{bench_with, BenchConfig};
+use adventofcode_2023::cancel::with_timeout;
+use adventofcode_2023::{cancellation_token, cancelled, days, run_with, Opt};
+use clap::Parser;
+use common::TempDir;
+
+/// The timeout of parts that are meant to time out.
+const TIMEOUT: Option<Duration> = Some(Duration::from_millis(100));
+/// The timeout of parts that aren't, which is long enough for a busy machine, and for the panic
+/// hook to print a backtrace.
+const GENEROUS: Option<Duration> = Some(Duration::from_secs(30));
+
+#[test]
+fn returns_parts_that_finish_in_time() {
+    assert_eq!(with_timeout(GENEROUS, || 42), Some(42));
+    assert_eq!(with_timeout(None, || 42), Some(42));
+}
+
+#[test]
+fn cancels_parts_that_time_out() {
+    let (tx, rx) = mpsc::channel();
+
+    let result = with_timeout(TIMEOUT, move || {
+        // a worker thread only sees the cancellation through the token it is given
+        let token = cancellation_token();
+        let worker = thread::spawn(move || {
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+        });
+        while !cancelled() {
+            thread::sleep(Duration::from_millis(1));
+        }
+        worker.join().unwrap();
+        tx.send(()).unwrap();
+    });
+
+    assert_eq!(result, None);
+    rx.recv_timeout(Duration::from_secs(5))
+        .expect("the part should stop once cancelled");
+    assert!(!cancelled());
+}
+
+#[test]
+#[should_panic(expected = "the part panicked")]
+fn resumes_panics_of_parts() {
+    with_timeout(GENEROUS, || panic!("the part panicked"));
+}
+
+#[test]
+fn reports_parts_that_time_out() {
+    let dir = TempDir::new("cancel-run");
+    let path = dir.join("unreachable.txt");
+    // going left forever never reaches ZZZ
+    std::fs::write(&path, "L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    let path = path.to_str().unwrap();
+    let opt = Opt::try_parse_from(["day8", "--timeout", "0.1", "--input", path]).unwrap();
+    let report = run_with(days::find(8).unwrap(), &opt).unwrap().remove(0);
+
+    for part in &report.parts {
+        assert_eq!(part.answer, None);
+        assert_eq!(part.status(), "timed_out");
+        assert_eq!(part.to_json()["answer"], serde_json::Value::Null);
+    }
+
+    assert!(Opt::try_parse_from(["day8", "--timeout", "-1"]).is_err());
+}
+
+#[test]
+fn benchmarks_parts_against_the_timeout_of_a_single_run() {
+    let dir = TempDir::new("cancel-bench");
+    let path = dir.join("unreachable.txt");
+    std::fs::write(&path, "L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    // the whole benchmark takes longer than the timeout, but every run is much faster
+    let config = BenchConfig {
+        warmup: 0,
+        iterations: 1_000_000,
+        budget: Duration::from_millis(1500),
+    };
+    let opt = Opt::try_parse_from(["day6", "--timeout", "1"]).unwrap();
+    let report = bench_with(days::find(6).unwrap(), &opt, &config)
+        .unwrap()
+        .remove(0);
+    for (_, stats) in &report.parts {
+        assert!(stats.is_some());
+    }
+
+    let path = path.to_str().unwrap();
+    let opt = Opt::try_parse_from(["day8", "--timeout", "0.1", "--input", path]).unwrap();
+    let report = bench_with(days::find(8).unwrap(), &opt, &config)
+        .unwrap()
+        .remove(0);
+    assert_eq!(report.parts.len(), 2);
+    for (i, (_, stats)) in report.parts.iter().enumerate() {
+        assert!(stats.is_none());
+        assert_eq!(report.to_json()["parts"][i]["status"], "timed_out");
+    }
+}
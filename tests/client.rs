@@ -0,0 +1,58 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use adventofcode_2023::client::{self, Cache, MIN_INTERVAL};
+use common::TempDir;
+
+#[test]
+fn identifies_the_tool() {
+    let (base_url, server) = common::serve_once(200, "ok");
+
+    client::get(&base_url, "token").unwrap();
+    let request = server.join().unwrap().to_lowercase();
+
+    assert!(request.contains("user-agent: github.com/dehanjl/adventofcode-2023"));
+}
+
+#[test]
+fn throttles_requests_to_the_same_site() {
+    let (base_url, server) = common::serve(&[(200, "first"), (200, "second")]);
+
+    let start = Instant::now();
+    client::get(&format!("{base_url}/first"), "token").unwrap();
+    client::get(&format!("{base_url}/second"), "token").unwrap();
+    server.join().unwrap();
+
+    assert!(start.elapsed() >= MIN_INTERVAL);
+}
+
+#[test]
+fn never_refetches_cached_responses() {
+    let dir = TempDir::new("cache-refetch");
+    let cache = Cache::new(dir.to_path_buf());
+    let (base_url, server) = common::serve_once(200, "puzzle");
+    let url = format!("{base_url}/2023/day/1");
+
+    assert_eq!(cache.fetch(&url, "token", None).unwrap(), "puzzle");
+    server.join().unwrap();
+
+    // the server is gone, so this can only succeed from the cache
+    assert_eq!(cache.fetch(&url, "token", None).unwrap(), "puzzle");
+    assert_eq!(
+        cache.get(&url, Some(Duration::from_secs(60))).unwrap(),
+        "puzzle"
+    );
+    assert_eq!(cache.get(&url, Some(Duration::ZERO)), None);
+}
+
+#[test]
+fn does_not_cache_errors() {
+    let dir = TempDir::new("cache-errors");
+    let cache = Cache::new(dir.to_path_buf());
+    let (base_url, _) = common::serve_once(500, "Internal Server Error");
+    let url = format!("{base_url}/2023/day/1");
+
+    assert!(cache.fetch(&url, "token", None).is_err());
+    assert_eq!(cache.get(&url, None), None);
+}
@@ -0,0 +1,133 @@
+//! A local stand-in for the Advent of Code website, and scratch directories for test files.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use adventofcode_2023::history::Entry;
+use adventofcode_2023::Part;
+
+/// A fresh directory for the files of a single test, which is removed again when dropped.
+///
+/// The name has to be unique across the tests of a file, since they run in parallel.
+#[allow(dead_code)]
+pub struct TempDir(PathBuf);
+
+#[allow(dead_code)]
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// An entry of the benchmark history of 2023, whose input is named after the day.
+#[allow(dead_code)]
+pub fn entry(commit: Option<&str>, day: u8, part: Part, kind: &str, median: Duration) -> Entry {
+    Entry {
+        commit: commit.map(str::to_owned),
+        timestamp: 0,
+        year: 2023,
+        day,
+        part,
+        input: format!("day{day}"),
+        kind: kind.to_owned(),
+        median,
+        mean: median,
+    }
+}
+
+/// Serves a single HTTP request with the given status and body.
+///
+/// Returns the base URL of the server, and a handle that resolves to the raw request that was
+/// received once it has been served.
+#[allow(dead_code)]
+pub fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+    let (base_url, handle) = serve(&[(status, body)]);
+    (
+        base_url,
+        thread::spawn(move || handle.join().unwrap().remove(0)),
+    )
+}
+
+/// Serves one HTTP request for each of the given responses, in order.
+///
+/// Returns the base URL of the server, and a handle that resolves to the raw requests that were
+/// received once they have all been served.
+#[allow(dead_code)]
+pub fn serve(responses: &[(u16, &str)]) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let responses = responses
+        .iter()
+        .map(|&(status, body)| (status, body.to_owned()))
+        .collect::<Vec<_>>();
+
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| serve_request(&listener, status, &body))
+            .collect()
+    });
+
+    (base_url, handle)
+}
+
+fn serve_request(listener: &TcpListener, status: u16, body: &str) -> String {
+    let (stream, _) = listener.accept().unwrap();
+    let mut reader = BufReader::new(stream);
+
+    let mut request = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+        request.push_str(&line);
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+    }
+
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content).unwrap();
+    request.push_str(&String::from_utf8(content).unwrap());
+
+    let response = format!(
+        "HTTP/1.1 {status} Stand-in\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+    request
+}
@@ -0,0 +1,56 @@
+//! Regression tests that run every example input against the answers in `inputs/<year>/answers.json`.
+//!
+//! A test is generated by `build.rs` for every file in `inputs/<year>/example`. The day is taken
+//! from the file name, so `day10_complex.txt` is checked against day 10 using the answers stored
+//! under `day10_complex` in the manifest. Only the parts that have an expected answer are run, and
+//! empty example files are skipped.
+
+use std::fs;
+
+use adventofcode_2023::days;
+use adventofcode_2023::manifest::Manifest;
+use adventofcode_2023::{inputs_dir, Part};
+
+fn check_example(year: u16, name: &str) {
+    let day = name
+        .strip_prefix("day")
+        .and_then(|rest| rest.split('_').next())
+        .and_then(|day| day.parse::<u8>().ok())
+        .unwrap_or_else(|| panic!("Couldn't find the day in the example name `{name}`"));
+    let solution =
+        days::find(day).unwrap_or_else(|| panic!("No solution is registered for day {day}"));
+    assert_eq!(
+        solution.year(),
+        year,
+        "The solution for day {day} is for another year"
+    );
+
+    let path = inputs_dir(year).join("example").join(format!("{name}.txt"));
+    let input = fs::read_to_string(path).expect("Couldn't read the example input");
+    if input.trim().is_empty() {
+        // a new day starts with an empty example, which isn't checked until it is filled in
+        return;
+    }
+
+    let manifest = Manifest::load(year).expect("Couldn't load the manifest of answers");
+    let expected = Part::BOTH
+        .into_iter()
+        .filter_map(|part| Some((part, manifest.expected(false, name, part)?)))
+        .collect::<Vec<_>>();
+    assert!(
+        !expected.is_empty(),
+        "`{name}` has no expected answers in the manifest"
+    );
+
+    let parsed = solution.parse(&input);
+
+    for (part, expected) in expected {
+        let answer = solution.solve(part, parsed.as_ref());
+        assert!(
+            expected.matches(&answer),
+            "`{name}` part {part} gave {answer}, expected {expected:?}"
+        );
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
@@ -0,0 +1,118 @@
+mod common;
+
+use std::time::Duration;
+
+use adventofcode_2023::history::{compare, Entry};
+use adventofcode_2023::Part;
+
+fn entry(commit: &str, day: u8, part: Part, kind: &str, median_ms: u64) -> Entry {
+    common::entry(
+        Some(commit),
+        day,
+        part,
+        kind,
+        Duration::from_millis(median_ms),
+    )
+}
+
+fn changes(entries: &[Entry], baseline: Option<&str>) -> Vec<(String, f64)> {
+    compare(entries, baseline, 0.05)
+        .into_iter()
+        .map(|c| {
+            (
+                c.baseline.commit.unwrap(),
+                (c.change * 1000.0).round() / 1000.0,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn compares_the_latest_entry_against_another_commit() {
+    let entries = [
+        entry("aaa", 1, Part::One, "real", 10),
+        entry("bbb", 1, Part::One, "real", 11),
+        entry("ccc", 1, Part::One, "real", 20),
+        entry("ccc", 1, Part::One, "real", 15),
+    ];
+
+    // entries from the same commit as the latest one are skipped, as are older ones
+    assert_eq!(changes(&entries, None), [("bbb".to_owned(), 0.364)]);
+    assert!(changes(&entries[2..], None).is_empty());
+}
+
+#[test]
+fn selects_the_baseline_by_commit_prefix() {
+    let entries = [
+        entry("abc1234", 1, Part::One, "real", 10),
+        entry("def5678", 1, Part::One, "real", 30),
+        entry("abc9999", 1, Part::One, "real", 40),
+        entry("fed0000", 1, Part::One, "real", 20),
+    ];
+
+    assert_eq!(
+        changes(&entries, Some("abc")),
+        [("abc9999".to_owned(), -0.5)]
+    );
+    assert_eq!(
+        changes(&entries, Some("abc1")),
+        [("abc1234".to_owned(), 1.0)]
+    );
+    assert!(changes(&entries, Some("123")).is_empty());
+}
+
+#[test]
+fn only_compares_entries_of_the_same_target() {
+    let mut other_input = entry("aaa", 1, Part::One, "real", 10);
+    other_input.input = "day1_part1".to_owned();
+    let mut other_year = entry("aaa", 1, Part::One, "real", 10);
+    other_year.year = 2022;
+    let entries = [
+        entry("aaa", 1, Part::One, "real", 10),
+        entry("aaa", 1, Part::Two, "real", 10),
+        entry("aaa", 1, Part::One, "example", 10),
+        entry("aaa", 2, Part::One, "real", 10),
+        other_input,
+        other_year,
+        entry("bbb", 2, Part::One, "real", 30),
+        entry("bbb", 1, Part::One, "real", 20),
+    ];
+
+    let comparisons = compare(&entries, None, 0.05);
+    let targets = comparisons
+        .iter()
+        .map(|c| (c.current.day, c.current.part, c.change))
+        .collect::<Vec<_>>();
+    // sorted by day, and every comparison is against the entry of the same target
+    assert_eq!(targets, [(1, Part::One, 1.0), (2, Part::One, 2.0)]);
+    for c in &comparisons {
+        assert_eq!(c.baseline.median, Duration::from_millis(10));
+        assert_eq!(c.baseline.kind, "real");
+        assert_eq!(c.baseline.input, c.current.input);
+        assert_eq!(c.baseline.year, 2023);
+    }
+}
+
+#[test]
+fn flags_regressions_above_the_threshold() {
+    let entries = [
+        entry("aaa", 1, Part::One, "real", 100),
+        entry("bbb", 1, Part::One, "real", 104),
+        entry("aaa", 2, Part::One, "real", 100),
+        entry("bbb", 2, Part::One, "real", 106),
+        entry("aaa", 3, Part::One, "real", 100),
+        entry("bbb", 3, Part::One, "real", 50),
+    ];
+
+    let regressed = compare(&entries, None, 0.05)
+        .iter()
+        .map(|c| (c.current.day, c.regressed))
+        .collect::<Vec<_>>();
+    assert_eq!(regressed, [(1, false), (2, true), (3, false)]);
+
+    let regressed = compare(&entries, None, 0.01)
+        .iter()
+        .filter(|c| c.regressed)
+        .count();
+    assert_eq!(regressed, 2);
+}
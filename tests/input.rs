@@ -0,0 +1,172 @@
+mod common;
+
+use adventofcode_2023::input::{fetch_input, normalize, InputError};
+use adventofcode_2023::{days, run_with, Answer, Opt, Part, Parts};
+use common::TempDir;
+
+#[test]
+fn fetches_input() {
+    let (base_url, server) = common::serve_once(200, "1abc2\npqr3stu8vwx\n");
+
+    let input = fetch_input(&base_url, "token", 2023, 1).unwrap();
+    let request = server.join().unwrap();
+
+    assert_eq!(input, "1abc2\npqr3stu8vwx\n");
+    assert!(request.starts_with("GET /2023/day/1/input "));
+    assert!(request.contains("session=token"));
+}
+
+#[test]
+fn rejects_html_pages() {
+    let (base_url, _) = common::serve_once(200, "<!DOCTYPE html>\n<html><body></body></html>");
+
+    let err = fetch_input(&base_url, "token", 2023, 1).unwrap_err();
+    assert!(matches!(err, InputError::Html));
+}
+
+#[test]
+fn rejects_expired_sessions() {
+    let (base_url, _) = common::serve_once(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    );
+
+    let err = fetch_input(&base_url, "token", 2023, 1).unwrap_err();
+    assert!(matches!(err, InputError::SessionRejected));
+
+    let (base_url, _) = common::serve_once(500, "Please log in to get your puzzle input.");
+
+    let err = fetch_input(&base_url, "token", 2023, 1).unwrap_err();
+    assert!(matches!(err, InputError::SessionRejected));
+}
+
+#[test]
+fn rejects_locked_days() {
+    let (base_url, _) = common::serve_once(404, "404 Not Found");
+
+    let err = fetch_input(&base_url, "token", 2023, 25).unwrap_err();
+    assert!(matches!(
+        err,
+        InputError::NotUnlocked {
+            year: 2023,
+            day: 25
+        }
+    ));
+}
+
+#[test]
+fn rejects_empty_inputs() {
+    let (base_url, _) = common::serve_once(200, "\n");
+
+    let err = fetch_input(&base_url, "token", 2023, 1).unwrap_err();
+    assert!(matches!(err, InputError::Empty));
+}
+
+#[test]
+fn reads_input_from_any_path() {
+    let dir = TempDir::new("input-gears");
+    let path = dir.join("gears.txt");
+    std::fs::write(
+        &path,
+        "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..\n",
+    )
+    .unwrap();
+
+    let opt = Opt {
+        input: Some(path),
+        ..Opt::default()
+    };
+    let report = run_with(days::find(3).unwrap(), &opt).unwrap().remove(0);
+
+    assert_eq!(report.input, "gears");
+    assert_eq!(report.parts[0].answer, Some(Answer::from(4361)));
+    assert_eq!(report.parts[1].answer, Some(Answer::from(467835)));
+
+    // named like an example, but it isn't one, so there is nothing to verify it against
+    let path = dir.join("day3.txt");
+    std::fs::write(&path, "1*1\n").unwrap();
+    let opt = Opt {
+        input: Some(path),
+        verify: true,
+        ..Opt::default()
+    };
+    let report = run_with(days::find(3).unwrap(), &opt).unwrap().remove(0);
+    assert!(report.parts.iter().all(|part| part.verdict.is_none()));
+}
+
+#[test]
+fn normalizes_inputs() {
+    assert_eq!(normalize("#.#\n.#.\n"), ("#.#\n.#.\n".into(), vec![]));
+    assert_eq!(normalize("#.#\n.#."), ("#.#\n.#.".into(), vec![]));
+
+    let (input, changes) = normalize("\u{feff}#.#\r\n.#.\r\n\r\n\r\n");
+    assert_eq!(input, "#.#\n.#.\n");
+    assert_eq!(
+        changes,
+        [
+            "removed the byte order mark",
+            "converted CRLF line endings to LF",
+            "removed trailing blank lines"
+        ]
+    );
+}
+
+#[test]
+fn runs_inputs_saved_on_windows() {
+    let dir = TempDir::new("input-mirrors");
+    let path = dir.join("mirrors.txt");
+    let unix =
+        std::fs::read_to_string(adventofcode_2023::inputs_dir(2023).join("example/day13.txt"))
+            .unwrap();
+    std::fs::write(
+        &path,
+        format!("\u{feff}{}\r\n\r\n", unix.replace('\n', "\r\n")),
+    )
+    .unwrap();
+
+    let opt = Opt {
+        input: Some(path),
+        ..Opt::default()
+    };
+    let report = run_with(days::find(13).unwrap(), &opt).unwrap().remove(0);
+
+    assert_eq!(report.parts[0].answer, Some(Answer::from(405)));
+    assert_eq!(report.parts[1].answer, Some(Answer::from(400)));
+}
+
+#[test]
+fn selects_example_input_per_part() {
+    let day1 = days::find(1).unwrap();
+
+    let reports = run_with(day1, &Opt::default()).unwrap();
+    let runs = reports
+        .iter()
+        .map(|r| (r.input.as_str(), r.parts[0].part, r.parts[0].answer.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        [
+            ("day1_part1", Part::One, Some(Answer::from(142))),
+            ("day1", Part::Two, Some(Answer::from(281)))
+        ]
+    );
+
+    let opt = Opt {
+        part: Parts::Two,
+        ..Opt::default()
+    };
+    let reports = run_with(day1, &opt).unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].input, "day1");
+    assert_eq!(reports[0].parts.len(), 1);
+
+    let opt = Opt {
+        alt: Some("day1_part1".into()),
+        part: Parts::One,
+        ..Opt::default()
+    };
+    assert_eq!(
+        run_with(day1, &opt).unwrap()[0].parts[0].answer,
+        Some(Answer::from(142))
+    );
+}
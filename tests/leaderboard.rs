@@ -0,0 +1,98 @@
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use adventofcode_2023::client::Cache;
+use adventofcode_2023::leaderboard::{self, Leaderboard};
+use common::TempDir;
+
+fn fixture() -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/leaderboard.json");
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn ranks_members_by_local_score() {
+    let leaderboard = Leaderboard::parse(&fixture()).unwrap();
+
+    assert_eq!(leaderboard.year, 2023);
+    let names = leaderboard
+        .members
+        .iter()
+        .map(|m| m.display_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Jane Doe", "(anonymous user #1002)", "Bob"]);
+    assert_eq!(leaderboard.days(), [1, 2]);
+}
+
+#[test]
+fn measures_solve_times_from_the_unlock() {
+    let leaderboard = Leaderboard::parse(&fixture()).unwrap();
+    let [jane, anonymous, bob] = &leaderboard.members[..] else {
+        panic!("expected three members");
+    };
+
+    let mins = |m: u64| Some(Duration::from_secs(m * 60));
+    assert_eq!(
+        jane.solve_times(2023, 1),
+        [
+            Some(Duration::from_secs(312)),
+            Some(Duration::from_secs(580))
+        ]
+    );
+    assert_eq!(jane.solve_times(2023, 2), [mins(10), mins(20)]);
+    assert_eq!(anonymous.solve_times(2023, 1), [mins(60), None]);
+    assert_eq!(bob.solve_times(2023, 1), [None, None]);
+}
+
+#[test]
+fn renders_ranking_and_times() {
+    let rendered = Leaderboard::parse(&fixture()).unwrap().render();
+    let expected = "\
+Rank  Score  Stars  Name
+   1     10      4  Jane Doe
+   2      5      2  (anonymous user #1002)
+   3      0      0  Bob
+
+Day 1                        Part 1       Part 2        Delta
+Jane Doe                   00:05:12     00:09:40     00:04:28
+(anonymous user #1002)     01:00:00            -            -
+
+Day 2                        Part 1       Part 2        Delta
+Jane Doe                   00:10:00     00:20:00     00:10:00
+(anonymous user #1002)       1d 03h            -            -
+";
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn fetches_private_leaderboard() {
+    let dir = TempDir::new("leaderboard");
+    let (base_url, server) = common::serve_once(200, &fixture());
+
+    let json = leaderboard::fetch(
+        &Cache::new(dir.to_path_buf()),
+        &base_url,
+        "token",
+        2023,
+        1001,
+    )
+    .unwrap();
+    let request = server.join().unwrap();
+
+    assert_eq!(json, fixture());
+    assert!(request.starts_with("GET /2023/leaderboard/private/view/1001.json "));
+}
+
+#[test]
+fn does_not_cache_pages_that_are_not_leaderboards() {
+    let dir = TempDir::new("leaderboard-login");
+    let cache = Cache::new(dir.to_path_buf());
+    let (base_url, _) = common::serve_once(200, "<!DOCTYPE html><html>Log in</html>");
+
+    assert!(leaderboard::fetch(&cache, &base_url, "expired", 2023, 1001).is_err());
+    let url = format!("{base_url}/2023/leaderboard/private/view/1001.json");
+    assert_eq!(cache.get(&url, None), None);
+}
@@ -0,0 +1,36 @@
+//! Only built with the `memory` feature, which installs the counting allocator.
+#![cfg(feature = "memory")]
+
+use std::hint::black_box;
+
+use adventofcode_2023::memory::measure;
+use adventofcode_2023::{days, run_with, Opt};
+use clap::Parser;
+
+// a single test, since allocations are counted on every thread while measuring
+#[test]
+fn measures_allocations_of_parts() {
+    let (_, stats) = measure(|| {
+        let mut v = black_box(Vec::<u8>::with_capacity(1 << 20));
+        v.resize(2 << 20, 0);
+        drop(v);
+        black_box(vec![0u8; 1 << 10]);
+    });
+    assert!(stats.peak >= 2 << 20, "{stats:?}");
+    assert!(stats.peak < 3 << 20, "{stats:?}");
+    assert!(stats.allocations >= 3, "{stats:?}");
+    assert!(stats.allocated >= (3 << 20) + (1 << 10), "{stats:?}");
+
+    let opt = Opt::try_parse_from(["day14", "--memory"]).unwrap();
+    let report = run_with(days::find(14).unwrap(), &opt).unwrap().remove(0);
+    for part in &report.parts {
+        let memory = part.memory.expect("memory should be measured");
+        assert!(memory.allocations > 0, "{memory:?}");
+        assert_eq!(part.to_json()["memory"]["allocations"], memory.allocations);
+    }
+
+    let report = run_with(days::find(14).unwrap(), &Opt::default())
+        .unwrap()
+        .remove(0);
+    assert!(report.parts.iter().all(|part| part.memory.is_none()));
+}
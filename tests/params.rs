@@ -0,0 +1,40 @@
+use adventofcode_2023::{days, run_with, Answer, Opt};
+use clap::Parser;
+
+// a single test, since the parameters of the solution being run are global
+#[test]
+fn changes_parameters_from_the_command_line() {
+    let day11 = days::find(11).unwrap();
+    let answer = |args: &[&str]| {
+        let opt = Opt::try_parse_from([&["day11", "--part", "2"], args].concat()).unwrap();
+        run_with(day11, &opt).unwrap()[0].parts[0]
+            .answer
+            .clone()
+            .unwrap()
+    };
+
+    assert_eq!(answer(&["--param", "part2_factor=10"]), Answer::from(1030));
+    assert_eq!(answer(&["--param", "part2_factor=100"]), Answer::from(8410));
+    assert_eq!(answer(&[]), Answer::from(82000210));
+
+    let opt = Opt::try_parse_from(["day11", "--param", "unfold=3"]).unwrap();
+    let err = run_with(day11, &opt).unwrap_err().to_string();
+    assert!(err.starts_with("day 11 has no parameter `unfold`"), "{err}");
+    assert!(
+        err.contains("part2_factor (default 1000000, from 1 to 1000000000)"),
+        "{err}"
+    );
+
+    for value in ["0", "1000000001", "18446744073709551615"] {
+        let param = format!("part2_factor={value}");
+        let opt = Opt::try_parse_from(["day11", "--param", &param]).unwrap();
+        let err = run_with(day11, &opt).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            format!("the parameter `part2_factor` of day 11 has to be from 1 to 1000000000, not {value}")
+        );
+    }
+
+    assert!(Opt::try_parse_from(["day11", "--param", "part2_factor"]).is_err());
+    assert!(Opt::try_parse_from(["day11", "--param", "part2_factor=-1"]).is_err());
+}
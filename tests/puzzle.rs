@@ -0,0 +1,131 @@
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use adventofcode_2023::client::Cache;
+use adventofcode_2023::manifest::{Expected, Manifest};
+use adventofcode_2023::puzzle::{self, Puzzle};
+use adventofcode_2023::Part;
+use common::TempDir;
+
+fn fixture(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_both_parts() {
+    let puzzle = Puzzle::parse(1, &fixture("puzzle_complete.html"));
+
+    assert!(puzzle.is_complete());
+    assert_eq!(puzzle.title.as_deref(), Some("Trebuchet?!"));
+    assert_eq!(
+        puzzle.named_examples(),
+        [
+            ("day1".into(), "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"),
+            (
+                "day1_part2".into(),
+                "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n"
+            ),
+        ]
+    );
+    assert_eq!(
+        puzzle.named_answers(),
+        [
+            ("day1".into(), Part::One, "142"),
+            ("day1_part2".into(), Part::Two, "281")
+        ]
+    );
+}
+
+#[test]
+fn converts_articles_to_markdown() {
+    let markdown = Puzzle::parse(1, &fixture("puzzle_complete.html")).to_markdown();
+
+    assert!(markdown.starts_with("## --- Day 1: Trebuchet?! ---\n"));
+    assert!(markdown.contains("a specific *calibration value* that"));
+    assert!(markdown.contains("```\n1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n```"));
+    assert!(markdown.contains("Adding these together produces **`142`**."));
+    assert!(markdown.contains("[calibration document](1/input)"));
+    assert!(markdown.contains("`one`, `two` & so on."));
+    assert!(markdown.contains("## --- Part Two ---"));
+    assert!(!markdown.contains("Your puzzle answer was"));
+    assert!(!markdown.contains('<'));
+}
+
+#[test]
+fn parses_only_the_first_part() {
+    let puzzle = Puzzle::parse(10, &fixture("puzzle_part1.html"));
+
+    assert!(!puzzle.is_complete());
+    assert_eq!(puzzle.title.as_deref(), Some("Pipe Maze"));
+    let names = puzzle
+        .named_examples()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["day10", "day10_2"]);
+    assert_eq!(puzzle.named_answers(), [("day10".into(), Part::One, "8")]);
+}
+
+#[test]
+fn saves_without_overwriting() {
+    let dir = TempDir::new("puzzle-save");
+    fs::create_dir_all(dir.join("example")).unwrap();
+    fs::write(dir.join("example/day1.txt"), "kept").unwrap();
+    fs::write(
+        dir.join("answers.json"),
+        r#"{"example": {"day1": {"part1": "1"}}, "real": {}}"#,
+    )
+    .unwrap();
+
+    let puzzle = Puzzle::parse(1, &fixture("puzzle_complete.html"));
+    let saved = puzzle::save(&puzzle, &dir).unwrap();
+
+    assert_eq!(saved.skipped, [dir.join("example/day1.txt")]);
+    assert_eq!(saved.examples, [dir.join("example/day1_part2.txt")]);
+    assert_eq!(
+        saved.answers,
+        [("day1_part2".into(), Part::Two, "281".into())]
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("example/day1.txt")).unwrap(),
+        "kept"
+    );
+    assert!(fs::read_to_string(dir.join("puzzles/day1.md"))
+        .unwrap()
+        .contains("Trebuchet"));
+
+    let manifest = Manifest::parse(&fs::read_to_string(dir.join("answers.json")).unwrap()).unwrap();
+    let expected = |input, part| manifest.expected(false, input, part).cloned();
+    assert_eq!(
+        expected("day1", Part::One),
+        Some(Expected::Plain("1".into()))
+    );
+    assert_eq!(
+        expected("day1_part2", Part::Two),
+        Some(Expected::Plain("281".into()))
+    );
+}
+
+#[test]
+fn caches_only_complete_puzzles() {
+    let dir = TempDir::new("puzzle-cache");
+    let cache = Cache::new(dir.to_path_buf());
+
+    let (base_url, _) = common::serve_once(200, &fixture("puzzle_part1.html"));
+    let puzzle = puzzle::fetch(&cache, &base_url, "token", 2023, 10).unwrap();
+    assert!(!puzzle.is_complete());
+    assert_eq!(cache.get(&format!("{base_url}/2023/day/10"), None), None);
+
+    let (base_url, server) = common::serve_once(200, &fixture("puzzle_complete.html"));
+    puzzle::fetch(&cache, &base_url, "token", 2023, 1).unwrap();
+    assert!(server.join().unwrap().starts_with("GET /2023/day/1 "));
+
+    // the server is gone, so this can only succeed from the cache
+    let puzzle = puzzle::fetch(&cache, &base_url, "token", 2023, 1).unwrap();
+    assert!(puzzle.is_complete());
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::fs;
+
+use adventofcode_2023::{scaffold, YEAR};
+use common::TempDir;
+
+/// A registry like `src/days/mod.rs`, which doesn't change as days are added to the repository.
+const REGISTRY: &str = "use crate::DynSolution;
+
+pub mod day1;
+pub mod day14;
+pub mod day2;
+
+static REGISTRY: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day14::Day14,
+];
+";
+
+/// The parts of the repository that a new day touches.
+fn test_root(name: &str) -> TempDir {
+    let root = TempDir::new(&format!("scaffold-{name}"));
+    fs::create_dir_all(root.join("src/days")).unwrap();
+    fs::create_dir_all(root.join("src/bin")).unwrap();
+    fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+    root
+}
+
+#[test]
+fn creates_a_new_day() {
+    let root = test_root("create");
+
+    let created = scaffold::create(&root, 2023, 15, Some("Lens Library")).unwrap();
+
+    assert_eq!(
+        created,
+        [
+            root.join("src/days/day15.rs"),
+            root.join("src/bin/day15.rs"),
+            root.join("src/days/mod.rs"),
+            root.join("inputs/2023/example/day15.txt"),
+        ]
+    );
+    let solution = fs::read_to_string(root.join("src/days/day15.rs")).unwrap();
+    assert!(solution.contains("pub struct Day15;"));
+    assert!(solution.contains("Some(\"Lens Library\")"));
+    assert!(solution.contains("#[cfg(test)]\nmod tests {"));
+    // the day is registered right away, so it has to run without panicking
+    assert!(solution.contains("Answer::from(\"unsolved\")"));
+    assert!(!solution.contains("todo!"));
+    assert!(!solution.contains("fn year"));
+    let wrapper = fs::read_to_string(root.join("src/bin/day15.rs")).unwrap();
+    assert!(wrapper.contains("run(&Day15);"));
+    let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(registry.contains("pub mod day14;\npub mod day15;\npub mod day2;\n"));
+    assert!(registry.contains("    &day14::Day14,\n    &day15::Day15,\n];"));
+    assert_eq!(
+        fs::read_to_string(root.join("inputs/2023/example/day15.txt")).unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn refuses_to_overwrite_a_day() {
+    let root = test_root("overwrite");
+    fs::write(root.join("src/bin/day15.rs"), "kept").unwrap();
+
+    assert!(scaffold::create(&root, 2023, 15, None).is_err());
+    assert!(scaffold::create(&root, 2023, 26, None).is_err());
+    assert!(scaffold::register(
+        &fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+        2
+    )
+    .is_err());
+
+    assert_eq!(
+        fs::read_to_string(root.join("src/bin/day15.rs")).unwrap(),
+        "kept"
+    );
+    assert!(!root.join("src/days/day15.rs").exists());
+}
+
+#[test]
+fn overrides_the_year_of_other_years() {
+    let solution = scaffold::solution_template(2022, 3, None, false);
+
+    assert!(solution.contains("fn year(&self) -> u16 {\n        2022\n    }"));
+    assert!(!solution.contains("fn title"));
+    assert!(!solution.contains("fn example_input"));
+}
+
+#[test]
+fn runs_part_2_on_its_own_example() {
+    let root = test_root("part2");
+    fs::create_dir_all(root.join("inputs/2023/example")).unwrap();
+    fs::write(root.join("inputs/2023/example/day15_part2.txt"), "HASH").unwrap();
+
+    scaffold::create(&root, 2023, 15, None).unwrap();
+
+    let solution = fs::read_to_string(root.join("src/days/day15.rs")).unwrap();
+    assert!(solution.starts_with("use crate::{Answer, Part, Solution};"));
+    assert!(solution.contains("Part::Two => Some(\"day15_part2\"),"));
+}
+
+#[test]
+fn documents_the_generated_files() {
+    let readme =
+        fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin/README.md")).unwrap();
+    let blocks = readme
+        .split("```rust\n")
+        .skip(1)
+        .map(|block| block.split("```").next().unwrap())
+        .collect::<Vec<_>>();
+
+    // the README uses X for the day
+    let generated = [
+        scaffold::wrapper_template(99),
+        scaffold::solution_template(YEAR, 99, None, false),
+    ]
+    .map(|template| template.replace("99", "X"));
+    assert_eq!(blocks, generated);
+}
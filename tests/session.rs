@@ -0,0 +1,100 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use adventofcode_2023::session::{check, save_token, SessionError, SessionStatus, Source, Sources};
+use common::TempDir;
+
+fn sources(dir: &Path) -> Sources {
+    Sources {
+        env: None,
+        dotenv: Some(dir.join(".env")),
+        config: Some(dir.join("config")),
+        token_file: Some(dir.join("session")),
+    }
+}
+
+#[test]
+fn resolves_sources_in_order() {
+    let dir = TempDir::new("session-order");
+    let mut sources = sources(&dir);
+    assert!(matches!(sources.resolve(), Err(SessionError::NotFound)));
+
+    save_token(&dir.join("session"), "from-file").unwrap();
+    let found = sources.resolve().unwrap();
+    assert_eq!(
+        found,
+        ("from-file".into(), Source::TokenFile(dir.join("session")))
+    );
+
+    fs::write(dir.join("config"), "# comment\nsession = \"from-config\"\n").unwrap();
+    let found = sources.resolve().unwrap();
+    assert_eq!(
+        found,
+        ("from-config".into(), Source::Config(dir.join("config")))
+    );
+
+    fs::write(
+        dir.join(".env"),
+        "OTHER=1\nexport AOC_SESSION=from-dotenv\n",
+    )
+    .unwrap();
+    let found = sources.resolve().unwrap();
+    assert_eq!(
+        found,
+        ("from-dotenv".into(), Source::DotEnv(dir.join(".env")))
+    );
+
+    sources.env = Some("from-env".into());
+    assert_eq!(sources.resolve().unwrap(), ("from-env".into(), Source::Env));
+}
+
+#[cfg(unix)]
+#[test]
+fn refuses_readable_token_files() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new("session-permissions");
+    let path = dir.join("session");
+    fs::write(&path, "token").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+    let err = sources(&dir).resolve().unwrap_err();
+    assert!(matches!(err, SessionError::Permissions { mode: 0o644, .. }));
+
+    save_token(&path, "token").unwrap();
+    assert_eq!(
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+    assert_eq!(sources(&dir).resolve().unwrap().0, "token");
+}
+
+#[test]
+fn checks_valid_token() {
+    let (base_url, server) = common::serve_once(
+        200,
+        r#"<html><header><div class="user">Jane Doe <span class="star-count">50*</span></div></header></html>"#,
+    );
+
+    let status = check(&base_url, "token", 2023).unwrap();
+    let request = server.join().unwrap();
+
+    assert_eq!(status, SessionStatus::Valid("Jane Doe".into()));
+    assert!(request.starts_with("GET /2023 "));
+    assert!(request.contains("session=token"));
+}
+
+#[test]
+fn checks_expired_token() {
+    let (base_url, _) = common::serve_once(
+        200,
+        r#"<html><header><a href="/2023/auth/login">[Log In]</a></header></html>"#,
+    );
+
+    assert_eq!(
+        check(&base_url, "token", 2023).unwrap(),
+        SessionStatus::Expired
+    );
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use std::fs;
+use std::time::Duration;
+
+use adventofcode_2023::status::{self, DayStatus};
+use adventofcode_2023::{days, Part};
+use common::{entry, TempDir};
+
+#[test]
+fn collects_and_renders_progress() {
+    let dir = TempDir::new("status");
+    fs::create_dir_all(dir.join("example")).unwrap();
+    fs::create_dir_all(dir.join("real")).unwrap();
+    for name in ["day1", "day1_part1", "day10_simple", "day10_complex"] {
+        fs::write(dir.join("example").join(format!("{name}.txt")), "").unwrap();
+    }
+    fs::write(dir.join("real/day1.txt"), "").unwrap();
+    fs::write(
+        dir.join("answers.json"),
+        r#"{"real": {"day1": {"part1": "sha256:00", "part2": "1"}}}"#,
+    )
+    .unwrap();
+
+    let history = [
+        entry(None, 1, Part::One, "real", Duration::from_micros(100)),
+        entry(None, 1, Part::Two, "real", Duration::from_micros(250)),
+        entry(None, 1, Part::One, "example", Duration::from_micros(5)),
+        entry(None, 10, Part::One, "example", Duration::from_micros(1500)),
+    ];
+    let days = status::collect(2023, &dir, &history).unwrap();
+
+    // solutions come from the registry, which grows as days are added
+    let solved = |day| days::find(day).is_some();
+    let s = |day| if solved(day) { 'S' } else { '.' };
+
+    assert_eq!(days.len(), 25);
+    assert_eq!(
+        days[0],
+        DayStatus {
+            day: 1,
+            solution: solved(1),
+            examples: 2,
+            real: true,
+            answers: 2,
+            timing: Some(Duration::from_micros(350)),
+        }
+    );
+    assert_eq!(days[9].examples, 2);
+    assert_eq!(days[9].timing, Some(Duration::from_micros(1500)));
+    assert_eq!(
+        days[24],
+        DayStatus {
+            day: 25,
+            solution: solved(25),
+            ..DayStatus::default()
+        }
+    );
+
+    let rendered = status::render(2023, &days);
+    let lines = rendered.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "Advent of Code 2023");
+    assert!(lines[1].starts_with(&format!(" 1 {}ER**   350µs |  2 {}.. ", s(1), s(2))));
+    assert!(lines[2].ends_with(&format!(" | 10 {}E.     1.5ms", s(10))));
+    assert!(lines[5].ends_with(&format!(" | 25 {}..", s(25))));
+    assert!(rendered.ends_with(&format!(
+        "{} solutions, 2 with examples, 1 with real inputs, 2 verified stars\n",
+        days::registry().len()
+    )));
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use std::time::Duration;
+
+use adventofcode_2023::submit::{parse_response, submit, Submission};
+use adventofcode_2023::{Answer, Part};
+
+fn page(article: &str) -> String {
+    format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+}
+
+#[test]
+fn submits_answer_as_form() {
+    let (base_url, server) = common::serve_once(
+        200,
+        &page(
+            "That's the right answer! You are one gold star closer to restoring snow operations.",
+        ),
+    );
+
+    let submission = submit(&base_url, "token", 2023, 5, Part::Two, &Answer::from(46)).unwrap();
+    let request = server.join().unwrap();
+
+    assert_eq!(submission, Submission::Correct);
+    assert!(request.starts_with("POST /2023/day/5/answer "));
+    assert!(request.contains("session=token"));
+    assert!(request.ends_with("level=2&answer=46"));
+}
+
+#[test]
+fn parses_wrong_answers() {
+    let wrong =
+        "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+    let high = "That's not the right answer; your answer is too high. Please wait one minute before trying again.";
+    let low = "That's not the right answer; your answer is too low.";
+
+    assert_eq!(parse_response(&page(wrong)), Submission::Wrong);
+    assert_eq!(parse_response(&page(high)), Submission::TooHigh);
+    assert_eq!(parse_response(&page(low)), Submission::TooLow);
+}
+
+#[test]
+fn parses_rate_limit() {
+    let minutes = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 4m 50s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+    let seconds = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 37s left to wait.";
+
+    assert_eq!(
+        parse_response(&page(minutes)),
+        Submission::RateLimited(Duration::from_secs(290))
+    );
+    assert_eq!(
+        parse_response(&page(seconds)),
+        Submission::RateLimited(Duration::from_secs(37))
+    );
+}
+
+#[test]
+fn parses_already_solved() {
+    let solved = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a>";
+
+    assert_eq!(parse_response(&page(solved)), Submission::AlreadySolved);
+}
+
+#[test]
+fn keeps_unknown_responses() {
+    assert_eq!(
+        parse_response("<article><p>Something <em>new</em></p></article>"),
+        Submission::Unknown("Something new".to_owned())
+    );
+}